@@ -14,7 +14,6 @@ fn main() {
     // Set the straight dotted line
     let mut line = Plot2D::new();
     line.coordinates = (0..11)
-        .into_iter()
         .map(|i| (f64::from(i), 2.0 * PI * f64::from(i)).into())
         .collect();
     line.add_key(PlotKey::Custom(String::from("dashed")));
@@ -58,6 +57,8 @@ fn main() {
 
     #[cfg(feature = "inclusive")]
     picture.show_with(&Compiler::Tectonic).unwrap();
+    // Any installed engine works e.g. `Engine::XeLatex` or `Engine::LuaLatex`
+    // for Unicode labels.
     #[cfg(not(feature = "inclusive"))]
     picture
         .show_with(&Compiler::Installed(Engine::PdfLatex))
@@ -0,0 +1,50 @@
+use pgfplots::axis::{
+    plot::{Plot3D, PlotKey, Type3D, UnboundedCoords},
+    Axis,
+};
+
+use pgfplots::Compiler;
+#[cfg(not(feature = "inclusive"))]
+use pgfplots::Engine;
+use pgfplots::Picture;
+
+fn main() {
+    const N: i32 = 25;
+
+    // Sample z = x^2 + y^2 on a square grid, one row of constant x at a time.
+    // Coordinates close to the origin are NaN, which cuts a hole in the
+    // bottom of the surface.
+    let mut surface = Plot3D::new();
+    for i in 0..N {
+        for j in 0..N {
+            let x = -2.0 + 4.0 * f64::from(i) / f64::from(N - 1);
+            let y = -2.0 + 4.0 * f64::from(j) / f64::from(N - 1);
+            let z = x * x + y * y;
+            if z < 0.5 {
+                surface.push((x, y, f64::NAN));
+            } else {
+                surface.push((x, y, z));
+            }
+        }
+    }
+    surface.add_key(PlotKey::Type3D(Type3D::Surf));
+    surface.add_key(PlotKey::MeshRows(N as usize));
+    surface.add_key(PlotKey::UnboundedCoords(UnboundedCoords::Jump));
+
+    let mut axis = Axis::new();
+    axis.set_title("Paraboloid");
+    axis.set_labels("$x$", "$y$");
+    axis.set_z_label("$z = x^2 + y^2$");
+    axis.set_view(30.0, 35.0);
+    axis.plots_3d.push(surface);
+
+    let mut picture = Picture::new();
+    picture.axes.push(axis);
+
+    #[cfg(feature = "inclusive")]
+    picture.show_with(&Compiler::Tectonic).unwrap();
+    #[cfg(not(feature = "inclusive"))]
+    picture
+        .show_with(&Compiler::Installed(Engine::PdfLatex))
+        .unwrap();
+}
@@ -12,14 +12,12 @@ fn main() {
     // Set line
     let mut line = Plot2D::new();
     line.coordinates = (0..101)
-        .into_iter()
         .map(|i| (f64::from(i), f64::from(i * i)).into())
         .collect();
 
     // Set rectangles
     let mut rectangles = Plot2D::new();
     rectangles.coordinates = (0..101)
-        .into_iter()
         .step_by(10)
         .map(|i| (f64::from(i), f64::from(i * i)).into())
         .collect();
@@ -0,0 +1,47 @@
+use pgfplots::axis::{
+    plot::{Plot2D, PlotKey},
+    Axis, AxisKey, Stacking,
+};
+
+use pgfplots::Compiler;
+#[cfg(not(feature = "inclusive"))]
+use pgfplots::Engine;
+use pgfplots::Picture;
+
+fn main() {
+    let years = [2020.0, 2021.0, 2022.0, 2023.0];
+    let series = [
+        ("Coal", [40.0, 35.0, 30.0, 22.0]),
+        ("Gas", [25.0, 28.0, 27.0, 26.0]),
+        ("Renewables", [10.0, 15.0, 22.0, 31.0]),
+    ];
+
+    let mut axis = Axis::new();
+    axis.set_title("Electricity generation");
+    axis.set_x_label("Year");
+    axis.set_y_label("Generation~[TWh]");
+    // Reverse the legend, so its entries are in the same order as the bars.
+    axis.add_key(AxisKey::BarStacking(Stacking::StackedReversed));
+    axis.add_key(AxisKey::XTick(years.to_vec()));
+    axis.add_key(AxisKey::Custom(String::from(
+        "xticklabel style={/pgf/number format/1000 sep=}",
+    )));
+    axis.add_key(AxisKey::YMin(0.0));
+    // Every series has a value for every year, so the bars line up.
+    for (name, values) in series {
+        let plot: Plot2D = years.into_iter().zip(values).collect();
+        axis.plots
+            .push(plot.with_key(PlotKey::AreaLegend).with_legend_entry(name));
+    }
+
+    let mut picture = Picture::new();
+    picture.axes.push(axis);
+    assert_eq!(picture.validate(), Ok(()));
+
+    #[cfg(feature = "inclusive")]
+    picture.show_with(&Compiler::Tectonic).unwrap();
+    #[cfg(not(feature = "inclusive"))]
+    picture
+        .show_with(&Compiler::Installed(Engine::PdfLatex))
+        .unwrap();
+}
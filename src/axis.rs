@@ -1,9 +1,17 @@
-use crate::axis::plot::Plot2D;
+use crate::axis::plot::{LineStyle, Plot2D, Plot3D, PlotKey};
+use crate::color::Color;
+use crate::units::Length;
+use crate::{latex_escape, Library, ValidationWarning};
 use std::fmt;
 
+/// Grid of aligned [`Axis`] environments.
+pub mod group;
 /// Plot inside an [`Axis`] environment.
 pub mod plot;
 
+/// Maximum number of ticks set by [`Axis::set_ticks_from_data`].
+pub const MAX_DATA_TICKS: usize = 50;
+
 /// PGFPlots options passed to the [`Axis`] environment.
 ///
 /// The most commonly used key-value pairs are variants of the [`AxisKey`] enum.
@@ -25,6 +33,174 @@ pub enum AxisKey {
     XLabel(String),
     /// Control the label of the *y* axis.
     YLabel(String),
+    /// Control the maximum distance between two adjacent ticks. Smaller
+    /// values produce more ticks on wide axes.
+    MaxSpaceBetweenTicks(Length),
+    /// Control the minimum number of ticks PGFPlots tries to place on each
+    /// axis.
+    TryMinTicks(usize),
+    /// Control whether markers of coordinates outside of the axis limits are
+    /// clipped. Set to `false` to draw such markers completely.
+    ClipLimits(bool),
+    /// Control how much the limits of both axes are enlarged beyond the range
+    /// of the data.
+    EnlargeLimits(EnlargeLimits),
+    /// Control how much the limits of the *x* axis are enlarged beyond the
+    /// range of the data.
+    EnlargeXLimits(EnlargeLimits),
+    /// Control how much the limits of the *y* axis are enlarged beyond the
+    /// range of the data.
+    EnlargeYLimits(EnlargeLimits),
+    /// Control the number of samples used by every plot of an expression
+    /// inside the axis.
+    Samples(usize),
+    /// Control the discontinuity marker drawn on the *x* axis line. Useful
+    /// when the axis does not start at zero.
+    AxisXDiscontinuity(AxisDiscontinuity),
+    /// Control the discontinuity marker drawn on the *y* axis line. Useful
+    /// when the axis does not start at zero.
+    AxisYDiscontinuity(AxisDiscontinuity),
+    /// Control the source of the point meta data, which is used e.g. to map
+    /// coordinates to colors of a colormap.
+    PointMeta(PointMeta),
+    /// Control which grid lines are drawn.
+    Grid(GridStyle),
+    /// Control the style of the major grid lines. This will be written
+    /// verbatim inside the `{...}` of the style.
+    MajorGridStyle(String),
+    /// Control the style of the minor grid lines. This will be written
+    /// verbatim inside the `{...}` of the style.
+    MinorGridStyle(String),
+    /// Control the style of the tick labels of all axes. This will be written
+    /// verbatim inside the `{...}` of the style.
+    TickLabelStyle(String),
+    /// Control the style of the tick labels of the *x* axis. This will be
+    /// written verbatim inside the `{...}` of the style.
+    XTickLabelStyle(String),
+    /// Control the style of the tick labels of the *y* axis. This will be
+    /// written verbatim inside the `{...}` of the style.
+    YTickLabelStyle(String),
+    /// Control whether PGFPlots writes a warning to the log for every
+    /// coordinate it discards e.g. due to a restricted domain.
+    FilterDiscardWarning(bool),
+    /// Shift the label of the *x* axis away from (positive) or towards
+    /// (negative) the axis.
+    XLabelShift(Length),
+    /// Shift the label of the *y* axis away from (positive) or towards
+    /// (negative) the axis.
+    YLabelShift(Length),
+    /// Control the orientation of the colorbar. If `true`, draw a horizontal
+    /// colorbar below the axis. Otherwise, draw a vertical colorbar to the
+    /// right of the axis.
+    ColorbarHorizontal(bool),
+    /// Draw the colorbar as a sampled line with the given style (e.g.
+    /// `scatter, only marks`) instead of a continuous bar. This will be
+    /// written verbatim inside the `{...}` of the style.
+    ColorbarSampleLine(String),
+    /// Append a style to the markers of every plot in the axis. This will be
+    /// written verbatim inside the `{...}` of the style.
+    EveryMarkStyle(String),
+    /// Control the style of the title of the axis. This will be written
+    /// verbatim inside the `{...}` of the style.
+    TitleStyle(String),
+    /// Width of the axis. Unless [`AxisKey::ScaleOnlyAxis`] is set, this
+    /// includes the labels and ticks.
+    Width(Length),
+    /// Height of the axis. Unless [`AxisKey::ScaleOnlyAxis`] is set, this
+    /// includes the labels and ticks.
+    Height(Length),
+    /// Control whether [`AxisKey::Width`] and [`AxisKey::Height`] apply only
+    /// to the axis box, ignoring the labels and ticks.
+    ScaleOnlyAxis(bool),
+    /// Size (in `pt`) of the markers of every plot in the axis.
+    EveryMarkScale(f64),
+    /// Positions of the major ticks of the *x* axis.
+    XTick(Vec<f64>),
+    /// TeX code that maps each *x* coordinate before it is processed e.g.
+    /// `\pgfmathparse{#1^2}`. This will be written verbatim inside the
+    /// `{...}` of the code key.
+    XCoordTrafo(String),
+    /// TeX code that maps each transformed *x* coordinate back e.g. to
+    /// compute the tick labels. This is the inverse of
+    /// [`AxisKey::XCoordTrafo`], and will be written verbatim inside the
+    /// `{...}` of the code key.
+    XCoordInvTrafo(String),
+    /// Control the horizontal alignment of the text of the legend entries.
+    LegendCellAlign(Align),
+    /// Label of the *z* axis. This is only written if the axis contains a
+    /// [`Plot3D`](plot::Plot3D).
+    ZLabel(String),
+    /// Point of view (in degrees) of a three-dimensional axis. This is only
+    /// written if the axis contains a [`Plot3D`](plot::Plot3D).
+    View { azimuth: f64, elevation: f64 },
+    /// Control on which side of the axis box the ticks of the *x* axis are
+    /// drawn. For the *x* axis, [`TickPos::Left`] is the bottom and
+    /// [`TickPos::Right`] is the top.
+    XTickPos(TickPos),
+    /// Control on which side of the axis box the ticks of the *y* axis are
+    /// drawn.
+    YTickPos(TickPos),
+    /// Basis of the logarithm of the *x* axis. This only has an effect if
+    /// [`AxisKey::XMode`] is [`Scale::Log`].
+    LogBasisX(f64),
+    /// Basis of the logarithm of the *y* axis. This only has an effect if
+    /// [`AxisKey::YMode`] is [`Scale::Log`].
+    LogBasisY(f64),
+    /// Control the position of the legend of the axis.
+    LegendPos(LegendPos),
+    /// Set the style of the label holding the common scaling factor of the
+    /// *y* tick labels e.g. to move the `\cdot 10^k` label.
+    EveryYTickScaleLabelStyle(String),
+    /// Set the lower limit of the *x* axis.
+    XMin(f64),
+    /// Set the upper limit of the *x* axis.
+    XMax(f64),
+    /// Set the lower limit of the *y* axis.
+    YMin(f64),
+    /// Set the upper limit of the *y* axis.
+    YMax(f64),
+    /// Draw every plot in the axis as vertical bars, placing the bars of
+    /// different plots side by side.
+    YBar,
+    /// Control whether the entries of the legend are listed in the reverse
+    /// order of the plots.
+    ReverseLegend(bool),
+    /// Control the style of the label of the *y* axis. This will be written
+    /// verbatim inside the `{...}` of the style.
+    YLabelStyle(String),
+    /// Control whether one unit has the same length along every axis. This
+    /// changes the limits of the axes to keep its width and height.
+    AxisEqual(bool),
+    /// Control whether one unit has the same length along every axis. This
+    /// changes the width or height of the axis to keep its limits.
+    AxisEqualImage(bool),
+    /// Control whether each axis line is drawn on its own instead of as part
+    /// of a closed box, so it can be moved away from the plot area.
+    SeparateAxisLines(bool),
+    /// Control the style of the line of the *x* axis. This will be written
+    /// verbatim inside the `{...}` of the style.
+    XAxisLineStyle(String),
+    /// Control the style of the line of the *y* axis. This will be written
+    /// verbatim inside the `{...}` of the style.
+    YAxisLineStyle(String),
+    /// Control whether the plots of the axis provide the range of the
+    /// colorbar. Use [`PlotKey::ColorbarSource`] to tie the colorbar to a
+    /// single plot instead.
+    ColorbarSource(bool),
+    /// Colormap used to map the point meta data of the plots to colors.
+    Colormap(Colormap),
+    /// Control whether a colorbar showing the colormap is drawn next to the
+    /// axis.
+    Colorbar(bool),
+    /// Distance between consecutive major ticks of the *x* axis.
+    XTickDistance(f64),
+    /// Distance between consecutive major ticks of the *y* axis.
+    YTickDistance(f64),
+    /// Draw every plot in the axis as vertical bars stacked on top of each
+    /// other. This already implies [`AxisKey::YBar`]. Stacked plots should have
+    /// the same *x* values and no bar shift, see
+    /// [`Picture::validate`](crate::Picture::validate).
+    BarStacking(Stacking),
 }
 
 impl fmt::Display for AxisKey {
@@ -36,6 +212,71 @@ impl fmt::Display for AxisKey {
             AxisKey::Title(value) => write!(f, "title={{{value}}}"),
             AxisKey::XLabel(value) => write!(f, "xlabel={{{value}}}"),
             AxisKey::YLabel(value) => write!(f, "ylabel={{{value}}}"),
+            AxisKey::MaxSpaceBetweenTicks(value) => write!(f, "max space between ticks={value}"),
+            AxisKey::TryMinTicks(value) => write!(f, "try min ticks={value}"),
+            AxisKey::ClipLimits(value) => write!(f, "clip limits={value}"),
+            AxisKey::EnlargeLimits(value) => write!(f, "enlargelimits={value}"),
+            AxisKey::EnlargeXLimits(value) => write!(f, "enlarge x limits={value}"),
+            AxisKey::EnlargeYLimits(value) => write!(f, "enlarge y limits={value}"),
+            AxisKey::Samples(value) => write!(f, "samples={value}"),
+            AxisKey::AxisXDiscontinuity(value) => write!(f, "axis x discontinuity={value}"),
+            AxisKey::AxisYDiscontinuity(value) => write!(f, "axis y discontinuity={value}"),
+            AxisKey::PointMeta(value) => write!(f, "point meta={value}"),
+            AxisKey::Grid(value) => write!(f, "grid={value}"),
+            AxisKey::MajorGridStyle(value) => write!(f, "major grid style={{{value}}}"),
+            AxisKey::MinorGridStyle(value) => write!(f, "minor grid style={{{value}}}"),
+            AxisKey::TickLabelStyle(value) => write!(f, "tick label style={{{value}}}"),
+            AxisKey::XTickLabelStyle(value) => write!(f, "x tick label style={{{value}}}"),
+            AxisKey::YTickLabelStyle(value) => write!(f, "y tick label style={{{value}}}"),
+            AxisKey::FilterDiscardWarning(value) => write!(f, "filter discard warning={value}"),
+            AxisKey::XLabelShift(value) => write!(f, "xlabel shift={value}"),
+            AxisKey::YLabelShift(value) => write!(f, "ylabel shift={value}"),
+            AxisKey::ColorbarHorizontal(true) => write!(f, "colorbar horizontal"),
+            AxisKey::ColorbarHorizontal(false) => write!(f, "colorbar right"),
+            AxisKey::ColorbarSampleLine(value) => write!(f, "colorbar sampled line={{{value}}}"),
+            AxisKey::EveryMarkStyle(value) => write!(f, "every mark/.append style={{{value}}}"),
+            AxisKey::TitleStyle(value) => write!(f, "title style={{{value}}}"),
+            AxisKey::Width(value) => write!(f, "width={value}"),
+            AxisKey::Height(value) => write!(f, "height={value}"),
+            AxisKey::ScaleOnlyAxis(value) => write!(f, "scale only axis={value}"),
+            AxisKey::EveryMarkScale(value) => {
+                write!(f, "every mark/.append style={{mark size={value}pt}}")
+            }
+            AxisKey::XTick(positions) => {
+                let positions: Vec<String> = positions.iter().map(ToString::to_string).collect();
+                write!(f, "xtick={{{}}}", positions.join(","))
+            }
+            AxisKey::XCoordTrafo(code) => write!(f, "x coord trafo/.code={{{code}}}"),
+            AxisKey::XCoordInvTrafo(code) => write!(f, "x coord inv trafo/.code={{{code}}}"),
+            AxisKey::LegendCellAlign(value) => write!(f, "legend cell align={value}"),
+            AxisKey::ZLabel(value) => write!(f, "zlabel={{{value}}}"),
+            AxisKey::View { azimuth, elevation } => write!(f, "view={{{azimuth}}}{{{elevation}}}"),
+            AxisKey::XTickPos(value) => write!(f, "xtick pos={value}"),
+            AxisKey::YTickPos(value) => write!(f, "ytick pos={value}"),
+            AxisKey::LogBasisX(value) => write!(f, "log basis x={value}"),
+            AxisKey::LogBasisY(value) => write!(f, "log basis y={value}"),
+            AxisKey::LegendPos(value) => write!(f, "legend pos={value}"),
+            AxisKey::EveryYTickScaleLabelStyle(value) => {
+                write!(f, "every y tick scale label/.style={{{value}}}")
+            }
+            AxisKey::XMin(value) => write!(f, "xmin={value}"),
+            AxisKey::XMax(value) => write!(f, "xmax={value}"),
+            AxisKey::YMin(value) => write!(f, "ymin={value}"),
+            AxisKey::YMax(value) => write!(f, "ymax={value}"),
+            AxisKey::YBar => write!(f, "ybar"),
+            AxisKey::ReverseLegend(value) => write!(f, "reverse legend={value}"),
+            AxisKey::YLabelStyle(value) => write!(f, "ylabel style={{{value}}}"),
+            AxisKey::AxisEqual(value) => write!(f, "axis equal={value}"),
+            AxisKey::AxisEqualImage(value) => write!(f, "axis equal image={value}"),
+            AxisKey::SeparateAxisLines(value) => write!(f, "separate axis lines={value}"),
+            AxisKey::XAxisLineStyle(value) => write!(f, "x axis line style={{{value}}}"),
+            AxisKey::YAxisLineStyle(value) => write!(f, "y axis line style={{{value}}}"),
+            AxisKey::ColorbarSource(value) => write!(f, "colorbar source={value}"),
+            AxisKey::Colormap(value) => write!(f, "colormap/{value}"),
+            AxisKey::Colorbar(value) => write!(f, "colorbar={value}"),
+            AxisKey::XTickDistance(value) => write!(f, "xtick distance={value}"),
+            AxisKey::YTickDistance(value) => write!(f, "ytick distance={value}"),
+            AxisKey::BarStacking(value) => write!(f, "{value}"),
         }
     }
 }
@@ -63,28 +304,91 @@ impl fmt::Display for AxisKey {
 #[derive(Clone, Debug, Default)]
 pub struct Axis {
     keys: Vec<AxisKey>,
+    background_image: Option<(String, (f64, f64, f64, f64))>,
+    // Names of the lower and upper paths of each fill between them.
+    fill_between: Vec<(String, String, FillBetweenOptions)>,
     pub plots: Vec<Plot2D>,
+    pub plots_3d: Vec<Plot3D>,
 }
 
 impl fmt::Display for Axis {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_environment(f, false)
+    }
+}
+
+impl Axis {
+    // Write the whole `axis` environment. If `overlays` is true, each plot
+    // with an overlay is wrapped in a Beamer `\only<i->`.
+    pub(crate) fn write_environment<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        overlays: bool,
+    ) -> fmt::Result {
         write!(f, "\\begin{{axis}}")?;
+        self.write_contents(f, overlays)?;
+        write!(f, "\\end{{axis}}")?;
+
+        Ok(())
+    }
+    // Write the keys and plots of the axis, without the surrounding
+    // environment. This is shared with the cells of a `GroupPlot`.
+    fn write_contents<W: fmt::Write>(&self, f: &mut W, overlays: bool) -> fmt::Result {
         // If there are keys, print one per line. It makes it easier for a
         // human to find individual keys later.
-        if !self.keys.is_empty() {
+        // Keys of a third dimension are skipped in a two-dimensional axis.
+        let is_3d = self.is_3d();
+        let mut keys = self
+            .keys
+            .iter()
+            .filter(|key| is_3d || !matches!(key, AxisKey::ZLabel(_) | AxisKey::View { .. }))
+            .peekable();
+        if keys.peek().is_some() {
             writeln!(f, "[")?;
-            for key in self.keys.iter() {
+            for key in keys {
                 writeln!(f, "\t{key},")?;
             }
             write!(f, "]")?;
         }
         writeln!(f)?;
 
+        // The image goes first, so every plot is drawn on top of it.
+        if let Some((path, (x_min, x_max, y_min, y_max))) = &self.background_image {
+            writeln!(
+                f,
+                "\t\\addplot graphics[xmin={x_min}, xmax={x_max}, ymin={y_min}, ymax={y_max}] {{{path}}};"
+            )?;
+        }
         for plot in self.plots.iter() {
+            // The legend entry is revealed together with its plot.
+            let overlay = plot.overlay().filter(|_| overlays);
+            let indent = if overlay.is_some() { "\t" } else { "" };
+            if let Some(slide) = overlay {
+                writeln!(f, "\t\\only<{slide}->{{")?;
+            }
+            for line in plot.to_string().lines() {
+                writeln!(f, "{indent}{line}")?;
+            }
+            // Forgotten plots are not in the legend.
+            if let Some(entry) = plot.legend_entry().filter(|_| !plot.is_forgotten()) {
+                writeln!(f, "{indent}\t\\addlegendentry{{{entry}}}")?;
+            }
+            if overlay.is_some() {
+                writeln!(f, "\t}}")?;
+            }
+        }
+        for plot in self.plots_3d.iter() {
             writeln!(f, "{plot}")?;
+            if let Some(entry) = plot.legend_entry().filter(|_| !plot.is_forgotten()) {
+                writeln!(f, "\t\\addlegendentry{{{entry}}}")?;
+            }
+        }
+        for (lower, upper, options) in self.fill_between.iter() {
+            writeln!(
+                f,
+                "\t\\addplot[{options}] fill between[of={lower} and {upper}];"
+            )?;
         }
-
-        write!(f, "\\end{{axis}}")?;
 
         Ok(())
     }
@@ -104,7 +408,8 @@ impl Axis {
         Default::default()
     }
     /// Set the title of the axis environment. This can be valid LaTeX e.g.
-    /// inline math.
+    /// inline math, and is written verbatim. Use [`Axis::set_title_text`] for
+    /// plain text with special characters.
     ///
     /// # Examples
     ///
@@ -117,7 +422,23 @@ impl Axis {
     pub fn set_title<S: Into<String>>(&mut self, title: S) {
         self.add_key(AxisKey::Title(title.into()));
     }
-    /// Set the label of the *x* axis. This can be valid LaTeX e.g. inline math.
+    /// Set the title of the axis environment to plain text, escaping the
+    /// characters with a special meaning in LaTeX (see [`latex_escape`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_title_text("Growth of x_1 (50%)");
+    /// ```
+    pub fn set_title_text(&mut self, title: &str) {
+        self.set_title(latex_escape(title));
+    }
+    /// Set the label of the *x* axis. This can be valid LaTeX e.g. inline math,
+    /// and is written verbatim. Use [`Axis::set_x_label_text`] for plain text
+    /// with special characters.
     ///
     /// # Examples
     ///
@@ -130,7 +451,23 @@ impl Axis {
     pub fn set_x_label<S: Into<String>>(&mut self, label: S) {
         self.add_key(AxisKey::XLabel(label.into()));
     }
-    /// Set the label of the *y* axis. This can be valid LaTeX e.g. inline math.
+    /// Set the label of the *x* axis to plain text, escaping the characters
+    /// with a special meaning in LaTeX (see [`latex_escape`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_x_label_text("time_since_start");
+    /// ```
+    pub fn set_x_label_text(&mut self, label: &str) {
+        self.set_x_label(latex_escape(label));
+    }
+    /// Set the label of the *y* axis. This can be valid LaTeX e.g. inline math,
+    /// and is written verbatim. Use [`Axis::set_y_label_text`] for plain text
+    /// with special characters.
     ///
     /// # Examples
     ///
@@ -143,6 +480,567 @@ impl Axis {
     pub fn set_y_label<S: Into<String>>(&mut self, label: S) {
         self.add_key(AxisKey::YLabel(label.into()));
     }
+    /// Set the label of the *y* axis to plain text, escaping the characters
+    /// with a special meaning in LaTeX (see [`latex_escape`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_y_label_text("Share of users [%]");
+    /// ```
+    pub fn set_y_label_text(&mut self, label: &str) {
+        self.set_y_label(latex_escape(label));
+    }
+    /// Set the label of the *y* axis, written horizontally above the top left
+    /// corner of the axis instead of rotated along it. This can be valid LaTeX
+    /// e.g. inline math.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_y_label_horizontal("$y$~[m]");
+    /// ```
+    pub fn set_y_label_horizontal<S: Into<String>>(&mut self, label: S) {
+        self.set_y_label(label);
+        self.add_key(AxisKey::YLabelStyle(String::from(
+            "rotate=-90, at={(axis description cs:0,1.05)}, anchor=south",
+        )));
+    }
+    /// Set the label of the *z* axis. This can be valid LaTeX e.g. inline math.
+    /// The label is only written if the axis contains a [`Plot3D`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_z_label("$z$~[m]");
+    /// ```
+    pub fn set_z_label<S: Into<String>>(&mut self, label: S) {
+        self.add_key(AxisKey::ZLabel(label.into()));
+    }
+    /// Set the point of view (in degrees) of the axis. The view is only
+    /// written if the axis contains a [`Plot3D`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_view(25.0, 30.0);
+    /// ```
+    pub fn set_view(&mut self, azimuth: f64, elevation: f64) {
+        self.add_key(AxisKey::View { azimuth, elevation });
+    }
+    /// Set the limits of the *x* axis. Only the bounds that are `Some` are
+    /// written; a `None` bound removes a previously set limit and is left for
+    /// PGFPlots to compute from the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_x_limits(Some(-1.0), None);
+    /// ```
+    pub fn set_x_limits(&mut self, min: Option<f64>, max: Option<f64>) {
+        self.keys
+            .retain(|key| !matches!(key, AxisKey::XMin(_) | AxisKey::XMax(_)));
+        if let Some(min) = min {
+            self.add_key(AxisKey::XMin(min));
+        }
+        if let Some(max) = max {
+            self.add_key(AxisKey::XMax(max));
+        }
+    }
+    /// Set the limits of the *y* axis. Only the bounds that are `Some` are
+    /// written; a `None` bound removes a previously set limit and is left for
+    /// PGFPlots to compute from the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_y_limits(Some(-1.0), None);
+    /// ```
+    pub fn set_y_limits(&mut self, min: Option<f64>, max: Option<f64>) {
+        self.keys
+            .retain(|key| !matches!(key, AxisKey::YMin(_) | AxisKey::YMax(_)));
+        if let Some(min) = min {
+            self.add_key(AxisKey::YMin(min));
+        }
+        if let Some(max) = max {
+            self.add_key(AxisKey::YMax(max));
+        }
+    }
+    /// Place the major ticks of the *x* axis at a fixed `distance` from each
+    /// other instead of at explicit positions. This removes any previous
+    /// [`AxisKey::XTick`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_x_tick_distance(2.0);
+    /// ```
+    pub fn set_x_tick_distance(&mut self, distance: f64) {
+        self.keys.retain(|key| !matches!(key, AxisKey::XTick(_)));
+        self.add_key(AxisKey::XTickDistance(distance));
+    }
+    /// Place the major ticks of the *y* axis at a fixed `distance` from each
+    /// other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_y_tick_distance(0.5);
+    /// ```
+    pub fn set_y_tick_distance(&mut self, distance: f64) {
+        self.add_key(AxisKey::YTickDistance(distance));
+    }
+    /// Give one unit the same length along both axes, keeping the width and
+    /// height of the axis. The limits are not enlarged, so the aspect ratio of
+    /// the data is exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.lock_aspect();
+    /// ```
+    pub fn lock_aspect(&mut self) {
+        self.keys
+            .retain(|key| !matches!(key, AxisKey::EnlargeXLimits(_) | AxisKey::EnlargeYLimits(_)));
+        self.add_key(AxisKey::AxisEqual(true));
+        self.add_key(AxisKey::AxisEqualImage(false));
+        self.add_key(AxisKey::EnlargeLimits(EnlargeLimits::False));
+    }
+    /// Set up the axis for a bar chart: every plot is drawn as vertical bars
+    /// sitting on `y = 0`, and the *x* limits are enlarged by 15% so the
+    /// outer bars are not clipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.prepare_for_bars();
+    /// ```
+    pub fn prepare_for_bars(&mut self) {
+        self.add_key(AxisKey::YBar);
+        self.add_key(AxisKey::EnlargeXLimits(EnlargeLimits::Rel(0.15)));
+        self.add_key(AxisKey::YMin(0.0));
+    }
+    /// Set the labels of the *x* and *y* axes. These can be valid LaTeX e.g.
+    /// inline math.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_labels("$x$~[m]", "$y$~[m]");
+    /// ```
+    pub fn set_labels<X: Into<String>, Y: Into<String>>(&mut self, x_label: X, y_label: Y) {
+        self.set_x_label(x_label);
+        self.set_y_label(y_label);
+    }
+    /// Set the title of the axis environment, and the labels of the *x* and
+    /// *y* axes. These can be valid LaTeX e.g. inline math.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_title_and_labels("Trajectory", "$x$~[m]", "$y$~[m]");
+    /// ```
+    pub fn set_title_and_labels<T: Into<String>, X: Into<String>, Y: Into<String>>(
+        &mut self,
+        title: T,
+        x_label: X,
+        y_label: Y,
+    ) {
+        self.set_title(title);
+        self.set_labels(x_label, y_label);
+    }
+    /// Draw major and minor grid lines with a dashed and dotted gray style
+    /// respectively. This is the grid style required by many journals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_dashed_grid();
+    /// ```
+    pub fn set_dashed_grid(&mut self) {
+        self.add_key(AxisKey::Grid(GridStyle::Both));
+        self.add_key(AxisKey::MajorGridStyle(String::from("dashed, gray!40")));
+        self.add_key(AxisKey::MinorGridStyle(String::from("dotted, gray!20")));
+    }
+    /// Set the font of the tick labels of all axes e.g. `\footnotesize`. Any
+    /// other option in the tick label style is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_tick_label_font("\\footnotesize");
+    /// ```
+    pub fn set_tick_label_font(&mut self, font: &str) {
+        self.merge_style_key(
+            "font",
+            font,
+            |key| match key {
+                AxisKey::TickLabelStyle(style) => Some(style),
+                _ => None,
+            },
+            AxisKey::TickLabelStyle,
+        );
+    }
+    /// Set the font of the tick labels of the *x* axis e.g. `\footnotesize`.
+    /// Any other option in the *x* tick label style is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_x_tick_label_font("\\footnotesize");
+    /// ```
+    pub fn set_x_tick_label_font(&mut self, font: &str) {
+        self.merge_style_key(
+            "font",
+            font,
+            |key| match key {
+                AxisKey::XTickLabelStyle(style) => Some(style),
+                _ => None,
+            },
+            AxisKey::XTickLabelStyle,
+        );
+    }
+    /// Set the font of the tick labels of the *y* axis e.g. `\footnotesize`.
+    /// Any other option in the *y* tick label style is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_y_tick_label_font("\\footnotesize");
+    /// ```
+    pub fn set_y_tick_label_font(&mut self, font: &str) {
+        self.merge_style_key(
+            "font",
+            font,
+            |key| match key {
+                AxisKey::YTickLabelStyle(style) => Some(style),
+                _ => None,
+            },
+            AxisKey::YTickLabelStyle,
+        );
+    }
+    /// Shift the title of the axis up (positive) or down (negative) e.g. to
+    /// avoid overlapping the top of the axis. Any other option in the title
+    /// style is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, units::Length};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_title_offset(Length::Pt(5.0));
+    /// ```
+    pub fn set_title_offset(&mut self, offset: Length) {
+        self.merge_style_key(
+            "yshift",
+            &offset.to_string(),
+            |key| match key {
+                AxisKey::TitleStyle(style) => Some(style),
+                _ => None,
+            },
+            AxisKey::TitleStyle,
+        );
+    }
+    /// Move the *x* and *y* axis lines outwards by `offset`, detached from
+    /// the plot area. Other options of the styles of the axis lines are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, units::Length};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.offset_axis_lines(Length::Pt(5.0));
+    /// ```
+    pub fn offset_axis_lines(&mut self, offset: Length) {
+        let shift = (-offset).to_string();
+        self.add_key(AxisKey::SeparateAxisLines(true));
+        self.merge_style_key(
+            "yshift",
+            &shift,
+            |key| match key {
+                AxisKey::XAxisLineStyle(style) => Some(style),
+                _ => None,
+            },
+            AxisKey::XAxisLineStyle,
+        );
+        self.merge_style_key(
+            "xshift",
+            &shift,
+            |key| match key {
+                AxisKey::YAxisLineStyle(style) => Some(style),
+                _ => None,
+            },
+            AxisKey::YAxisLineStyle,
+        );
+    }
+    // Set `option=value` in the style of the key matched by `find`, keeping any
+    // other option already in that style.
+    fn merge_style_key(
+        &mut self,
+        option: &str,
+        value: &str,
+        find: fn(&AxisKey) -> Option<&String>,
+        key: fn(String) -> AxisKey,
+    ) {
+        let style = merge_style(self.keys.iter().find_map(find), option, value);
+        self.add_key(key(style));
+    }
+    /// Set the width of the axis. Unless [`AxisKey::ScaleOnlyAxis`] is set,
+    /// this includes the labels and ticks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, units::Length};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_width(Length::TextWidth(0.8));
+    /// ```
+    pub fn set_width(&mut self, width: Length) {
+        self.add_key(AxisKey::Width(width));
+    }
+    /// Set the height of the axis. Unless [`AxisKey::ScaleOnlyAxis`] is set,
+    /// this includes the labels and ticks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, units::Length};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_height(Length::Cm(6.0));
+    /// ```
+    pub fn set_height(&mut self, height: Length) {
+        self.add_key(AxisKey::Height(height));
+    }
+    /// Set the size of the axis box, ignoring the labels and ticks. This is
+    /// useful to align the axes of multi-panel figures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, units::Length};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_axis_box_size(Length::Cm(6.0), Length::Cm(4.0));
+    /// ```
+    pub fn set_axis_box_size(&mut self, width: Length, height: Length) {
+        self.add_key(AxisKey::Width(width));
+        self.add_key(AxisKey::Height(height));
+        self.add_key(AxisKey::ScaleOnlyAxis(true));
+    }
+    /// Place the major ticks of the *x* axis at the *x* values of the
+    /// coordinates of every plot in the axis. If there are more than
+    /// [`MAX_DATA_TICKS`] unique values, only evenly spaced values among them
+    /// are used so that the tick labels do not overlap. Nothing is set if
+    /// there are no coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot2D, Axis};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.push((1.0, 2.0));
+    /// plot.push((4.0, 3.0));
+    ///
+    /// let mut axis = Axis::new().with_plot(plot);
+    /// axis.set_ticks_from_data();
+    /// ```
+    pub fn set_ticks_from_data(&mut self) {
+        let mut values: Vec<f64> = self
+            .plots
+            .iter()
+            .flat_map(|plot| plot.coordinates.iter().map(|c| c.x))
+            .filter(|x| x.is_finite())
+            .collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        values.dedup();
+        if values.is_empty() {
+            return;
+        }
+
+        let step = values.len().div_ceil(MAX_DATA_TICKS);
+        let ticks = values.into_iter().step_by(step).collect();
+        self.add_key(AxisKey::XTick(ticks));
+    }
+    /// Add a dashed gray `y = x` reference line spanning the combined range
+    /// of the *x* and *y* values of every plot in the axis e.g. to compare
+    /// two correlated variables. The line is left out of the legend. Nothing
+    /// is added if the axis has no finite coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot2D, Axis};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.push((1.0, 2.0));
+    /// plot.push((3.0, 0.5));
+    ///
+    /// let mut axis = Axis::new().with_plot(plot);
+    /// axis.add_identity_line();
+    /// assert_eq!(axis.plots[1].coordinates[0].x, 0.5);
+    /// assert_eq!(axis.plots[1].coordinates[1].y, 3.0);
+    /// ```
+    pub fn add_identity_line(&mut self) {
+        let (min, max) = self
+            .plots
+            .iter()
+            .flat_map(|plot| plot.coordinates.iter().flat_map(|c| [c.x, c.y]))
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        if min > max {
+            return;
+        }
+
+        let mut line = Plot2D::new()
+            .with_key(PlotKey::LineStyle(LineStyle::Dashed))
+            .with_key(PlotKey::Color(Color::Gray))
+            .with_key(PlotKey::ForgetPlot);
+        line.push((min, min));
+        line.push((max, max));
+        self.plots.push(line);
+    }
+    /// Shade the region between the plots at indices `lower` and `upper` of
+    /// [`Axis::plots`] e.g. to draw a confidence band. Plots without a
+    /// [`PlotKey::NamePath`] are given one. This requires the `fillbetween`
+    /// library, which is added automatically to
+    /// [`Picture::standalone_string`](crate::Picture::standalone_string).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lower` or `upper` is not the index of a plot in the axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot2D, Axis, FillBetweenOptions};
+    /// use pgfplots::color::Color;
+    ///
+    /// let mut axis = Axis::new()
+    ///     .with_plot(Plot2D::from_fn(0.0..=1.0, 10, |x| x - 0.1))
+    ///     .with_plot(Plot2D::from_fn(0.0..=1.0, 10, |x| x + 0.1));
+    /// axis.add_fill_between(
+    ///     0,
+    ///     1,
+    ///     FillBetweenOptions {
+    ///         fill: Some(Color::Blue),
+    ///         opacity: Some(0.2),
+    ///     },
+    /// );
+    /// ```
+    pub fn add_fill_between(&mut self, lower: usize, upper: usize, options: FillBetweenOptions) {
+        let plots = self.plots.len();
+        let mut name_path = |index: usize| {
+            let plot = self.plots.get_mut(index).unwrap_or_else(|| {
+                panic!("cannot fill between plot {index} of an axis with {plots} plots")
+            });
+            match plot.name_path() {
+                Some(name) => name.to_string(),
+                None => {
+                    let name = format!("plot{index}");
+                    plot.add_key(PlotKey::NamePath(name.clone()));
+                    name
+                }
+            }
+        };
+        let lower = name_path(lower);
+        let upper = name_path(upper);
+        self.fill_between.push((lower, upper, options));
+    }
+    /// Set the number of samples used by every plot of an expression inside
+    /// the axis. Individual plots can still override it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_samples(100);
+    /// ```
+    pub fn set_samples(&mut self, samples: usize) {
+        self.add_key(AxisKey::Samples(samples));
+    }
+    /// Draw an external image behind the plots of the axis e.g. to annotate a
+    /// photo. The `extent` is `(x_min, x_max, y_min, y_max)` in axis
+    /// coordinates. The picture is compiled in a temporary directory, so a
+    /// relative `path` is resolved against the current working directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_background_image("photo.png", (0.0, 640.0, 0.0, 480.0));
+    /// ```
+    pub fn set_background_image(&mut self, path: &str, extent: (f64, f64, f64, f64)) {
+        let path = std::path::Path::new(path);
+        let path = if path.is_relative() {
+            std::env::current_dir()
+                .map(|dir| dir.join(path))
+                .unwrap_or_else(|_| path.to_path_buf())
+        } else {
+            path.to_path_buf()
+        };
+        // LaTeX expects forward slashes, also on Windows.
+        let path = path.display().to_string().replace('\\', "/");
+        self.background_image = Some((path, extent));
+    }
     /// Add a key to control the appearance of the axis. This will overwrite
     /// any previous mutually exclusive key.
     ///
@@ -169,6 +1067,126 @@ impl Axis {
         }
         self.keys.push(key);
     }
+    /// Add a key to control the appearance of the axis, and return the axis.
+    /// This will overwrite any previous mutually exclusive key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{Axis, AxisKey, Scale::Log};
+    ///
+    /// let axis = Axis::new()
+    ///     .with_key(AxisKey::XMode(Log))
+    ///     .with_key(AxisKey::YMode(Log));
+    /// ```
+    pub fn with_key(mut self, key: AxisKey) -> Self {
+        self.add_key(key);
+        self
+    }
+    /// Add a plot to the axis, and return the axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot2D, Axis};
+    ///
+    /// let axis = Axis::new().with_plot(Plot2D::new()).with_plot(Plot2D::new());
+    /// ```
+    pub fn with_plot(mut self, plot: Plot2D) -> Self {
+        self.plots.push(plot);
+        self
+    }
+    /// Add a three-dimensional plot to the axis and return the axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot3D, Axis};
+    ///
+    /// let axis = Axis::new().with_plot_3d(Plot3D::new());
+    /// assert!(axis.is_3d());
+    /// ```
+    pub fn with_plot_3d(mut self, plot: Plot3D) -> Self {
+        self.plots_3d.push(plot);
+        self
+    }
+    /// Return `true` if the axis contains a three-dimensional plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot2D, Axis};
+    ///
+    /// let axis = Axis::new().with_plot(Plot2D::new());
+    /// assert!(!axis.is_3d());
+    /// ```
+    pub fn is_3d(&self) -> bool {
+        !self.plots_3d.is_empty()
+    }
+    // Libraries needed to compile the axis, without duplicates.
+    pub(crate) fn libraries(&self) -> Vec<Library> {
+        let mut libraries = Vec::new();
+        for library in self.plots.iter().flat_map(Plot2D::libraries) {
+            if !libraries.contains(&library) {
+                libraries.push(library);
+            }
+        }
+        if !self.fill_between.is_empty() {
+            libraries.push(Library::FillBetween);
+        }
+        libraries
+    }
+    // Whether any plot in the axis needs shell escape to be compiled.
+    pub(crate) fn requires_shell_escape(&self) -> bool {
+        self.plots.iter().any(Plot2D::requires_shell_escape)
+            || self.plots_3d.iter().any(Plot3D::requires_shell_escape)
+    }
+    // Push the warnings of every plot in the axis, which is at index `axis` of
+    // the picture.
+    pub(crate) fn validate(&self, axis: usize, warnings: &mut Vec<ValidationWarning>) {
+        let log_x = self
+            .keys
+            .iter()
+            .any(|key| matches!(key, AxisKey::XMode(Scale::Log)));
+        let log_y = self
+            .keys
+            .iter()
+            .any(|key| matches!(key, AxisKey::YMode(Scale::Log)));
+        for (index, plot) in self.plots.iter().enumerate() {
+            plot.validate(axis, index, log_x, log_y, warnings);
+        }
+
+        // PGFPlots adds the stacked values coordinate by coordinate, so every
+        // plot in the stack needs the same x values as the first one.
+        let stacked = self
+            .keys
+            .iter()
+            .any(|key| matches!(key, AxisKey::BarStacking(_)));
+        if stacked {
+            let mut stack = self
+                .plots
+                .iter()
+                .enumerate()
+                .filter(|(_, plot)| !plot.is_forgotten());
+            let x_values =
+                |plot: &Plot2D| -> Vec<f64> { plot.coordinates.iter().map(|c| c.x).collect() };
+            if let Some((first_index, first)) = stack.next() {
+                let first_x = x_values(first);
+                if first.is_shifted_bar() {
+                    warnings.push(ValidationWarning::MisalignedStackedBars {
+                        axis,
+                        plot: first_index,
+                    });
+                }
+                for (index, plot) in stack {
+                    if plot.is_shifted_bar() || x_values(plot) != first_x {
+                        warnings
+                            .push(ValidationWarning::MisalignedStackedBars { axis, plot: index });
+                    }
+                }
+            }
+        }
+    }
 }
 
 /// Control the scaling of an axis.
@@ -188,5 +1206,283 @@ impl fmt::Display for Scale {
     }
 }
 
+/// Options of the region shaded by [`Axis::add_fill_between`].
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::{axis::FillBetweenOptions, color::Color};
+///
+/// let options = FillBetweenOptions {
+///     fill: Some(Color::Red),
+///     ..Default::default()
+/// };
+/// assert_eq!(options.to_string(), "fill={red}");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FillBetweenOptions {
+    /// Color of the region. PGFPlots picks one from the cycle list if this
+    /// is `None`.
+    pub fill: Option<Color>,
+    /// Opacity (from 0 to 1) of the region.
+    pub opacity: Option<f64>,
+}
+impl fmt::Display for FillBetweenOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut options = Vec::new();
+        if let Some(fill) = &self.fill {
+            options.push(format!("fill={{{fill}}}"));
+        }
+        if let Some(opacity) = self.opacity {
+            options.push(format!("fill opacity={opacity}"));
+        }
+        write!(f, "{}", options.join(", "))
+    }
+}
+
+/// Control how much the limits of an axis are enlarged beyond the range of the
+/// data.
+#[derive(Clone, Copy, Debug)]
+pub enum EnlargeLimits {
+    /// Enlarge the limits by the PGFPlots default (10% of the data range).
+    True,
+    /// Set the limits tightly around the data.
+    False,
+    /// Enlarge the limits by a fraction of the data range, e.g. `0.1` adds 10%
+    /// to each side.
+    Rel(f64),
+    /// Enlarge the limits by a fixed amount in axis units on each side.
+    Abs(f64),
+}
+impl fmt::Display for EnlargeLimits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnlargeLimits::True => write!(f, "true"),
+            EnlargeLimits::False => write!(f, "false"),
+            EnlargeLimits::Rel(value) => write!(f, "{value}"),
+            EnlargeLimits::Abs(value) => write!(f, "{{abs={value}}}"),
+        }
+    }
+}
+
+/// Control the discontinuity marker of an axis line.
+#[derive(Clone, Copy, Debug)]
+pub enum AxisDiscontinuity {
+    /// Draw a continuous axis line.
+    None,
+    /// Draw a zig-zag "crunch" near the origin of the axis line.
+    Crunch,
+    /// Draw two parallel lines near the origin of the axis line.
+    Parallel,
+}
+impl fmt::Display for AxisDiscontinuity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AxisDiscontinuity::None => write!(f, "none"),
+            AxisDiscontinuity::Crunch => write!(f, "crunch"),
+            AxisDiscontinuity::Parallel => write!(f, "parallel"),
+        }
+    }
+}
+
+/// Control the source of the point meta data.
+#[derive(Clone, Debug)]
+pub enum PointMeta {
+    /// Use the value given explicitly for each coordinate.
+    Explicit,
+    /// Use the *x* value of each coordinate.
+    X,
+    /// Use the *y* value of each coordinate.
+    Y,
+    /// Compute the point meta data with a custom mathematical expression e.g.
+    /// `x*y`. This will be written verbatim.
+    F(String),
+}
+impl fmt::Display for PointMeta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PointMeta::Explicit => write!(f, "explicit"),
+            PointMeta::X => write!(f, "x"),
+            PointMeta::Y => write!(f, "y"),
+            PointMeta::F(expression) => write!(f, "{{{expression}}}"),
+        }
+    }
+}
+
+/// Colormap built into PGFPlots, used to map point meta data to colors.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum Colormap {
+    /// Perceptually uniform map from dark blue through green to yellow.
+    Viridis,
+    /// Map from black through red and yellow to white.
+    Hot,
+    /// Map from dark blue through cyan, yellow and red to dark red.
+    Jet,
+    /// Map from cyan to magenta.
+    Cool,
+    /// Map from blue through white to red.
+    BlueRed,
+    /// Map from black to white.
+    BlackWhite,
+}
+impl fmt::Display for Colormap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Colormap::Viridis => write!(f, "viridis"),
+            Colormap::Hot => write!(f, "hot"),
+            Colormap::Jet => write!(f, "jet"),
+            Colormap::Cool => write!(f, "cool"),
+            Colormap::BlueRed => write!(f, "bluered"),
+            Colormap::BlackWhite => write!(f, "blackwhite"),
+        }
+    }
+}
+
+/// Control which grid lines are drawn.
+#[derive(Clone, Copy, Debug)]
+pub enum GridStyle {
+    /// Draw no grid lines.
+    None,
+    /// Draw grid lines at the major ticks.
+    Major,
+    /// Draw grid lines at the minor ticks.
+    Minor,
+    /// Draw grid lines at both major and minor ticks.
+    Both,
+}
+impl fmt::Display for GridStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridStyle::None => write!(f, "none"),
+            GridStyle::Major => write!(f, "major"),
+            GridStyle::Minor => write!(f, "minor"),
+            GridStyle::Both => write!(f, "both"),
+        }
+    }
+}
+
+// Return `style` with `option=value` replacing any previous value of `option`.
+// Options are only split at top-level commas, so values like `{(0,1)}` are
+// kept intact.
+fn merge_style(style: Option<&String>, option: &str, value: &str) -> String {
+    let mut options = Vec::new();
+    if let Some(style) = style {
+        let mut depth = 0;
+        let mut start = 0;
+        for (i, c) in style.char_indices() {
+            match c {
+                '{' | '(' => depth += 1,
+                '}' | ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    options.push(&style[start..i]);
+                    start = i + 1;
+                }
+                _ => (),
+            }
+        }
+        options.push(&style[start..]);
+    }
+
+    let mut merged: Vec<String> = options
+        .into_iter()
+        .map(str::trim)
+        .filter(|o| !o.is_empty() && o.split('=').next().unwrap().trim() != option)
+        .map(String::from)
+        .collect();
+    merged.push(format!("{option}={value}"));
+    merged.join(", ")
+}
+
+/// Horizontal alignment of text.
+#[derive(Clone, Copy, Debug)]
+pub enum Align {
+    /// Align the text to the left.
+    Left,
+    /// Align the text to the right.
+    Right,
+    /// Center the text.
+    Center,
+}
+impl fmt::Display for Align {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Align::Left => write!(f, "left"),
+            Align::Right => write!(f, "right"),
+            Align::Center => write!(f, "center"),
+        }
+    }
+}
+
+/// Side of the axis box where ticks are drawn.
+#[derive(Clone, Copy, Debug)]
+pub enum TickPos {
+    /// Draw the ticks only on the left (or bottom) side.
+    Left,
+    /// Draw the ticks only on the right (or top) side.
+    Right,
+    /// Draw the ticks on both sides.
+    Both,
+}
+impl fmt::Display for TickPos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TickPos::Left => write!(f, "left"),
+            TickPos::Right => write!(f, "right"),
+            TickPos::Both => write!(f, "both"),
+        }
+    }
+}
+
+/// Control how the bars of different plots in an [`Axis`] are stacked.
+///
+/// The bars of each plot are stacked on top of those of the previous plots, so
+/// the first plot is at the bottom of the stack. The legend always follows the
+/// stack; the variants only choose the end it starts from, so there is no need
+/// for [`AxisKey::ReverseLegend`].
+#[derive(Clone, Copy, Debug)]
+pub enum Stacking {
+    /// The legend lists the entries from the bottom of the stack up, i.e. in
+    /// the order of the plots.
+    Stacked,
+    /// The legend lists the entries from the top of the stack down, i.e. in
+    /// the same order as the bars appear.
+    StackedReversed,
+}
+impl fmt::Display for Stacking {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stacking::Stacked => write!(f, "ybar stacked"),
+            Stacking::StackedReversed => write!(f, "ybar stacked, reverse legend"),
+        }
+    }
+}
+
+/// Position of the legend of an [`Axis`].
+#[derive(Clone, Copy, Debug)]
+pub enum LegendPos {
+    /// Inside the axis, in the bottom left corner.
+    SouthWest,
+    /// Inside the axis, in the bottom right corner.
+    SouthEast,
+    /// Inside the axis, in the top left corner.
+    NorthWest,
+    /// Inside the axis, in the top right corner.
+    NorthEast,
+    /// Outside the axis, next to its top right corner.
+    OuterNorthEast,
+}
+impl fmt::Display for LegendPos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LegendPos::SouthWest => write!(f, "south west"),
+            LegendPos::SouthEast => write!(f, "south east"),
+            LegendPos::NorthWest => write!(f, "north west"),
+            LegendPos::NorthEast => write!(f, "north east"),
+            LegendPos::OuterNorthEast => write!(f, "outer north east"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,153 @@
+use crate::axis::{Axis, AxisKey};
+use crate::Library;
+use std::fmt;
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::Picture;
+
+/// Grid of aligned axes inside a [`Picture`].
+///
+/// A [`GroupPlot`] is equivalent to the `groupplot` environment of the
+/// PGFPlots `groupplots` library:
+///
+/// ```text
+/// \begin{groupplot}[group style={group size=columns by rows}, AxisKeys]
+/// \nextgroupplot[AxisKeys]
+///     % plots
+/// \end{groupplot}
+/// ```
+///
+/// The axes fill the grid row by row. The keys of the group are shared by
+/// every axis, while the keys of each axis (e.g. its title) only apply to its
+/// own cell. If there are fewer axes than cells, the trailing cells are left
+/// empty. The library is added automatically to
+/// [`Picture::standalone_string`].
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::{group::GroupPlot, plot::Plot2D, Axis};
+///
+/// let mut group = GroupPlot::new(1, 2);
+/// for title in ["Left", "Right"] {
+///     let mut axis = Axis::new().with_plot(Plot2D::from_fn(0.0..=1.0, 5, |x| x));
+///     axis.set_title(title);
+///     group.axes.push(axis);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GroupPlot {
+    rows: usize,
+    columns: usize,
+    keys: Vec<AxisKey>,
+    pub axes: Vec<Axis>,
+}
+
+impl fmt::Display for GroupPlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_environment(f, false)
+    }
+}
+
+impl GroupPlot {
+    // Write the whole `groupplot` environment. If `overlays` is true, each
+    // plot with an overlay is wrapped in a Beamer `\only<i->`.
+    pub(crate) fn write_environment<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        overlays: bool,
+    ) -> fmt::Result {
+        // Extra axes go in additional rows, PGFPlots refuses to draw more
+        // axes than cells.
+        let rows = self.rows.max(self.axes.len().div_ceil(self.columns));
+        writeln!(f, "\\begin{{groupplot}}[")?;
+        writeln!(
+            f,
+            "\tgroup style={{group size={} by {rows}}},",
+            self.columns
+        )?;
+        for key in self.keys.iter() {
+            writeln!(f, "\t{key},")?;
+        }
+        writeln!(f, "]")?;
+
+        for axis in self.axes.iter() {
+            write!(f, "\\nextgroupplot")?;
+            axis.write_contents(f, overlays)?;
+        }
+
+        write!(f, "\\end{{groupplot}}")?;
+
+        Ok(())
+    }
+    /// Creates a new, empty grid with the given number of rows and columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` or `columns` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::group::GroupPlot;
+    ///
+    /// let group = GroupPlot::new(2, 3);
+    /// ```
+    pub fn new(rows: usize, columns: usize) -> Self {
+        assert!(
+            rows > 0 && columns > 0,
+            "a group plot needs at least one row and one column"
+        );
+        Self {
+            rows,
+            columns,
+            keys: Vec::new(),
+            axes: Vec::new(),
+        }
+    }
+    /// Add a key shared by every axis of the group. This will overwrite any
+    /// previous mutually exclusive key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::{group::GroupPlot, AxisKey}, units::Length};
+    ///
+    /// let mut group = GroupPlot::new(2, 2);
+    /// group.add_key(AxisKey::Width(Length::Cm(5.0)));
+    /// ```
+    pub fn add_key(&mut self, key: AxisKey) {
+        match key {
+            AxisKey::Custom(_) => (),
+            _ => {
+                if let Some(index) = self
+                    .keys
+                    .iter()
+                    .position(|k| std::mem::discriminant(k) == std::mem::discriminant(&key))
+                {
+                    self.keys.remove(index);
+                }
+            }
+        }
+        self.keys.push(key);
+    }
+    // Libraries needed to compile the group, without duplicates.
+    pub(crate) fn libraries(&self) -> Vec<Library> {
+        let mut libraries = vec![Library::GroupPlots];
+        for library in self.axes.iter().flat_map(Axis::libraries) {
+            if !libraries.contains(&library) {
+                libraries.push(library);
+            }
+        }
+        libraries
+    }
+    // Whether any axis in the group needs shell escape to be compiled.
+    pub(crate) fn requires_shell_escape(&self) -> bool {
+        self.axes.iter().any(Axis::requires_shell_escape)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,39 @@
+use super::*;
+use crate::axis::plot::Plot2D;
+use crate::units::Length;
+
+#[test]
+fn group_plot_to_string() {
+    let mut group = GroupPlot::new(2, 2);
+    group.add_key(AxisKey::Width(Length::Cm(5.0)));
+    let mut axis = Axis::new();
+    axis.set_title("A");
+    group.axes.push(axis);
+    group.axes.push(Axis::new().with_plot(Plot2D::new()));
+    assert_eq!(
+        group.to_string(),
+        "\\begin{groupplot}[\n\tgroup style={group size=2 by 2},\n\twidth=5cm,\n]\n\\nextgroupplot[\n\ttitle={A},\n]\n\\nextgroupplot\n\t\\addplot[] coordinates {\n\t};\n\\end{groupplot}"
+    );
+}
+
+#[test]
+fn group_plot_extra_rows() {
+    let mut group = GroupPlot::new(1, 2);
+    group.axes = vec![Axis::new(); 3];
+    assert!(group
+        .to_string()
+        .starts_with("\\begin{groupplot}[\n\tgroup style={group size=2 by 2},\n]\n"));
+    assert_eq!(group.to_string().matches("\\nextgroupplot").count(), 3);
+}
+
+#[test]
+#[should_panic(expected = "a group plot needs at least one row and one column")]
+fn group_plot_new_zero_columns() {
+    GroupPlot::new(1, 0);
+}
+
+#[test]
+fn group_plot_libraries() {
+    let group = GroupPlot::new(1, 1);
+    assert_eq!(group.libraries(), vec![Library::GroupPlots]);
+}
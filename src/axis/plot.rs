@@ -1,5 +1,11 @@
-use crate::axis::plot::coordinate::Coordinate2D;
+use crate::axis::plot::coordinate::{Coordinate2D, Coordinate3D, ParseCoordinateError};
+use crate::color::Color;
+use crate::{Library, ValidationWarning};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
 
 // Only imported for documentation. If you notice that this is no longer the
 // case, please change it.
@@ -23,21 +29,110 @@ pub enum PlotKey {
     /// Control the type of two dimensional plots.
     Type2D(Type2D),
     /// Control the character (absolute or relative) of the error bars of the
-    /// *x* coordinates. Note that error bars won't be drawn unless
-    /// [`PlotKey::XErrorDirection`] is also set.
+    /// *x* coordinates. If any coordinate has an *x* error, this defaults to
+    /// [`ErrorCharacter::Absolute`].
     XError(ErrorCharacter),
-    /// Control the direction of the error bars of the *x* coordinates.
-    /// Note that error bars won't be drawn unless [`PlotKey::XError`] is also
-    /// set.
+    /// Control the direction of the error bars of the *x* coordinates. If any
+    /// coordinate has an *x* error, this defaults to [`ErrorDirection::Both`].
+    /// Use [`ErrorDirection::None`] to hide the error bars.
     XErrorDirection(ErrorDirection),
     /// Control the character (absolute or relative) of the error bars of the
-    /// *y* coordinates. Note that error bars won't be drawn unless
-    /// [`PlotKey::YErrorDirection`] is also set.
+    /// *y* coordinates. If any coordinate has a *y* error, this defaults to
+    /// [`ErrorCharacter::Absolute`].
     YError(ErrorCharacter),
-    /// Control the direction of the error bars of the *y* coordinates.
-    /// Note that error bars won't be drawn unless [`PlotKey::YError`] is also
-    /// set.
+    /// Control the direction of the error bars of the *y* coordinates. If any
+    /// coordinate has a *y* error, this defaults to [`ErrorDirection::Both`].
+    /// Use [`ErrorDirection::None`] to hide the error bars.
     YErrorDirection(ErrorDirection),
+    /// Name of the table column with the error in the positive direction of
+    /// the *y* coordinates. This only applies to plots whose data is a table
+    /// with named columns.
+    YErrorPlus(String),
+    /// Name of the table column with the error in the negative direction of
+    /// the *y* coordinates. This only applies to plots whose data is a table
+    /// with named columns.
+    YErrorMinus(String),
+    /// Place a node with the value of each coordinate next to it.
+    NodesNearCoords,
+    /// Control how the nodes placed by [`PlotKey::NodesNearCoords`] are
+    /// aligned relative to their coordinates.
+    NodesNearCoordsAlign(NodesNearCoordsAlign),
+    /// Control the style of the nodes placed by [`PlotKey::NodesNearCoords`].
+    /// This will be written verbatim inside the `{...}` of the style.
+    NodesNearCoordsStyle(String),
+    /// Color each coordinate according to its [`Coordinate2D::class`]. Each
+    /// element is the style (e.g. `mark=square*, blue`) of the class with the
+    /// same index. Coordinates whose class has no style are drawn with the
+    /// default scatter style. Combine with [`Type2D::OnlyMarks`] to hide the
+    /// lines between coordinates.
+    ScatterClasses(Vec<String>),
+    /// Control how coordinates with non-finite values (e.g. [`f64::NAN`]) are
+    /// handled by this plot.
+    UnboundedCoords(UnboundedCoords),
+    /// Control how the corners of the plot line are drawn.
+    LineJoin(LineJoin),
+    /// Control how the end points of the plot line are drawn.
+    LineCap(LineCap),
+    /// Draw markers only at the coordinates with these indices. The first
+    /// coordinate of the plot has index 1.
+    MarkIndices(Vec<usize>),
+    /// Draw the coordinates as patches (filled polygons) of the given type,
+    /// taking consecutive coordinates as the vertices of each patch. This
+    /// requires the `patchplots` library, which is added automatically to
+    /// [`Picture::standalone_string`].
+    Patch(PatchType),
+    /// Append to the style of every node placed by
+    /// [`PlotKey::NodesNearCoords`]. This will be written verbatim inside the
+    /// `{...}` of the style.
+    EveryNodeNearCoordStyle(String),
+    /// Control the type of three dimensional plots.
+    Type3D(Type3D),
+    /// Number of rows of coordinates in a surface or mesh of a [`Plot3D`] i.e.
+    /// the number of scanlines used to connect the coordinates.
+    MeshRows(usize),
+    /// Opacity (from 0 to 1) of the filled areas of the plot.
+    FillOpacity(f64),
+    /// Opacity (from 0 to 1) of the lines of the plot.
+    DrawOpacity(f64),
+    /// Draw the legend image of the plot as a filled rectangle instead of a
+    /// line. This is useful for filled plots e.g. bars or areas.
+    AreaLegend,
+    /// Control the shape of the markers of the plot.
+    Mark(Mark),
+    /// Size (in `pt`) of the markers of the plot.
+    MarkSize(f64),
+    /// Control the dash pattern of the plot line.
+    LineStyle(LineStyle),
+    /// Control the width of the plot line.
+    LineWidth(LineWidth),
+    /// Color of the lines and markers of the plot.
+    Color(Color),
+    /// Leave the plot out of the legend and the cycle list of the axis e.g.
+    /// for reference lines.
+    ForgetPlot,
+    /// Name of the path of the plot, used to refer to it e.g. in
+    /// [`Axis::add_fill_between`](crate::axis::Axis::add_fill_between).
+    NamePath(String),
+    /// Comment written in the line(s) before the `\addplot` command instead
+    /// of its options e.g. to annotate the generated code. Each line of the
+    /// comment is prefixed with `%`.
+    Comment(String),
+    /// Mark the plot as the source of the colorbar, i.e. the colorbar shows
+    /// the range of this plot only. Useful when several plots share a
+    /// colormap.
+    ColorbarSource,
+    /// Draw a scatter plot where the color of each marker comes from the
+    /// [`Coordinate2D::meta`] value of its coordinate, mapped through the
+    /// colormap of the axis.
+    PointMetaColor,
+    /// Number of samples taken along the *x* domain when PGFPlots computes
+    /// the plot from a mathematical expression. Plots given by coordinates
+    /// are not affected.
+    Samples(usize),
+    /// Number of samples taken along the *y* domain of a surface computed
+    /// from a mathematical expression. If not set, PGFPlots uses the same
+    /// value as [`PlotKey::Samples`].
+    SamplesY(usize),
 }
 
 impl fmt::Display for PlotKey {
@@ -49,6 +144,59 @@ impl fmt::Display for PlotKey {
             PlotKey::XErrorDirection(value) => write!(f, "error bars/x dir={value}"),
             PlotKey::YError(value) => write!(f, "error bars/y {value}"),
             PlotKey::YErrorDirection(value) => write!(f, "error bars/y dir={value}"),
+            PlotKey::YErrorPlus(column) => write!(f, "y error plus=\\thisrow{{{column}}}"),
+            PlotKey::YErrorMinus(column) => write!(f, "y error minus=\\thisrow{{{column}}}"),
+            PlotKey::NodesNearCoords => write!(f, "nodes near coords"),
+            PlotKey::NodesNearCoordsAlign(value) => {
+                write!(f, "nodes near coords align={{{value}}}")
+            }
+            PlotKey::NodesNearCoordsStyle(value) => {
+                write!(f, "nodes near coords style={{{value}}}")
+            }
+            PlotKey::ScatterClasses(styles) => {
+                write!(
+                    f,
+                    "scatter, scatter src=explicit symbolic, scatter/classes={{"
+                )?;
+                for (class, style) in styles.iter().enumerate() {
+                    if class > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{class}={{{style}}}")?;
+                }
+                write!(f, "}}")
+            }
+            PlotKey::UnboundedCoords(value) => write!(f, "unbounded coords={value}"),
+            PlotKey::LineJoin(value) => write!(f, "line join={value}"),
+            PlotKey::LineCap(value) => write!(f, "line cap={value}"),
+            PlotKey::MarkIndices(indices) => {
+                let indices: Vec<String> = indices.iter().map(ToString::to_string).collect();
+                write!(f, "mark indices={{{}}}", indices.join(","))
+            }
+            PlotKey::Patch(value) => write!(f, "patch, patch type={value}"),
+            PlotKey::EveryNodeNearCoordStyle(value) => {
+                write!(f, "every node near coord/.append style={{{value}}}")
+            }
+            PlotKey::Type3D(value) => write!(f, "{value}"),
+            PlotKey::MeshRows(value) => write!(f, "mesh/rows={value}"),
+            PlotKey::FillOpacity(value) => write!(f, "fill opacity={value}"),
+            PlotKey::DrawOpacity(value) => write!(f, "draw opacity={value}"),
+            PlotKey::AreaLegend => write!(f, "area legend"),
+            PlotKey::Mark(value) => write!(f, "mark={value}"),
+            PlotKey::MarkSize(value) => write!(f, "mark size={value}pt"),
+            PlotKey::LineStyle(value) => write!(f, "{value}"),
+            PlotKey::LineWidth(value) => write!(f, "{value}"),
+            PlotKey::Color(value) => write!(f, "color={{{value}}}"),
+            PlotKey::ForgetPlot => write!(f, "forget plot"),
+            PlotKey::NamePath(value) => write!(f, "name path={value}"),
+            PlotKey::Comment(value) => {
+                let lines: Vec<String> = value.lines().map(|line| format!("% {line}")).collect();
+                write!(f, "{}", lines.join("\n"))
+            }
+            PlotKey::ColorbarSource => write!(f, "colorbar source"),
+            PlotKey::PointMetaColor => write!(f, "scatter, scatter src=explicit"),
+            PlotKey::Samples(value) => write!(f, "samples={value}"),
+            PlotKey::SamplesY(value) => write!(f, "samples y={value}"),
         }
     }
 }
@@ -79,17 +227,31 @@ impl fmt::Display for PlotKey {
 #[derive(Clone, Debug, Default)]
 pub struct Plot2D {
     keys: Vec<PlotKey>,
+    precision: Option<usize>,
+    legend_entry: Option<String>,
+    overlay: Option<usize>,
+    // Text written in brackets after the coordinates with the given style key.
+    // Only a few coordinates have one, if any.
+    coordinate_styles: BTreeMap<usize, String>,
     pub coordinates: Vec<Coordinate2D>,
 }
 
 impl fmt::Display for Plot2D {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_comments(f, &self.keys)?;
         write!(f, "\t\\addplot[")?;
         // If there are keys, print them one per line. It makes it easier for a
         // human to find individual keys later.
-        if !self.keys.is_empty() {
+        let keys: Vec<Cow<PlotKey>> = self
+            .keys
+            .iter()
+            .filter(|key| !matches!(key, PlotKey::Comment(_)))
+            .map(|key| self.padded_scatter_classes(key))
+            .chain(self.implied_error_keys().into_iter().map(Cow::Owned))
+            .collect();
+        if !keys.is_empty() {
             writeln!(f)?;
-            for key in self.keys.iter() {
+            for key in keys {
                 writeln!(f, "\t\t{key},")?;
             }
             write!(f, "\t")?;
@@ -97,7 +259,20 @@ impl fmt::Display for Plot2D {
         writeln!(f, "] coordinates {{")?;
 
         for coordinate in self.coordinates.iter() {
-            writeln!(f, "\t\t{coordinate}")?;
+            match self.precision {
+                Some(digits) => write!(f, "\t\t{}", coordinate.rounded(digits))?,
+                None => write!(f, "\t\t{coordinate}")?,
+            }
+            // The class and meta value are written in the same place.
+            if coordinate.class.is_none() && coordinate.meta.is_none() {
+                let style = coordinate
+                    .style
+                    .and_then(|key| self.coordinate_styles.get(&key));
+                if let Some(style) = style {
+                    write!(f, "\t[{style}]")?;
+                }
+            }
+            writeln!(f)?;
         }
 
         write!(f, "\t}};")?;
@@ -119,6 +294,165 @@ impl Plot2D {
     pub fn new() -> Self {
         Default::default()
     }
+    /// Creates a plot of `f` sampled at `samples` uniformly spaced *x* values
+    /// of the `domain`, including both endpoints. No samples give an empty
+    /// plot, and a single sample is taken at the start of the domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let plot = Plot2D::from_fn(0.0..=2.0, 5, |x| x * x);
+    /// assert_eq!(plot.coordinates.len(), 5);
+    /// assert_eq!(plot.coordinates[1].x, 0.5);
+    /// assert_eq!(plot.coordinates[4].y, 4.0);
+    /// ```
+    pub fn from_fn<F: Fn(f64) -> f64>(
+        domain: std::ops::RangeInclusive<f64>,
+        samples: usize,
+        f: F,
+    ) -> Self {
+        let (start, end) = domain.into_inner();
+        let coordinates = (0..samples)
+            .map(|i| {
+                // The last sample is exactly `end`, free of rounding errors.
+                let x = if i == 0 {
+                    start
+                } else if i == samples - 1 {
+                    end
+                } else {
+                    start + (end - start) * i as f64 / (samples - 1) as f64
+                };
+                (x, f(x)).into()
+            })
+            .collect();
+        Plot2D {
+            coordinates,
+            ..Default::default()
+        }
+    }
+    /// Creates a plot from two numeric columns of comma-separated values, with
+    /// one coordinate per row. Columns are counted from 0, and the first row
+    /// is skipped if `has_header` is `true`. Blank rows are skipped. Quoted
+    /// fields are not supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails, or if a row does not have a number
+    /// in one of the columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let data = "time,position,velocity\n0,1.5,0\n1,2,0.5\n";
+    /// let plot = Plot2D::from_csv(data.as_bytes(), 0, 1, true)?;
+    /// assert_eq!(plot.coordinates.len(), 2);
+    /// assert_eq!(plot.coordinates[1].y, 2.0);
+    /// # Ok::<(), pgfplots::axis::plot::CsvError>(())
+    /// ```
+    #[cfg(feature = "csv")]
+    pub fn from_csv<R: std::io::Read>(
+        reader: R,
+        x_col: usize,
+        y_col: usize,
+        has_header: bool,
+    ) -> Result<Plot2D, CsvError> {
+        use std::io::BufRead;
+
+        let mut plot = Plot2D::new();
+        for (index, line) in std::io::BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            if (has_header && index == 0) || line.trim().is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let value = |column: usize| {
+                let field = fields.get(column).ok_or(CsvError::MissingColumn {
+                    row: index + 1,
+                    column,
+                })?;
+                field
+                    .parse::<f64>()
+                    .map_err(|source| CsvError::InvalidNumber {
+                        row: index + 1,
+                        column,
+                        source,
+                    })
+            };
+            plot.coordinates.push((value(x_col)?, value(y_col)?).into());
+        }
+        Ok(plot)
+    }
+    /// Append a coordinate to the end of the plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.push((1.0, 2.0));
+    /// assert_eq!(plot.coordinates.len(), 1);
+    /// ```
+    pub fn push<C: Into<Coordinate2D>>(&mut self, coordinate: C) {
+        self.coordinates.push(coordinate.into());
+    }
+    /// Insert a coordinate at the beginning of the plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.push((1.0, 2.0));
+    /// plot.prepend((0.0, 1.0));
+    /// assert_eq!(plot.coordinates[0].x, 0.0);
+    /// ```
+    pub fn prepend<C: Into<Coordinate2D>>(&mut self, coordinate: C) {
+        self.coordinates.insert(0, coordinate.into());
+    }
+    /// Write `style` in brackets after the coordinate at `index` i.e.
+    /// `(x,y) [style]`. Most coordinates have none.
+    ///
+    /// PGFPlots reads the brackets as the point meta data of the coordinate,
+    /// so this does not change how the coordinate is drawn by itself; it
+    /// takes keys that use the point meta data e.g. `nodes near coords` or
+    /// `scatter/classes`. The text is ignored if the coordinate has a
+    /// [`Coordinate2D::class`] or [`Coordinate2D::meta`], which are written in
+    /// the same place. The style stays with the coordinate when
+    /// [`Plot2D::coordinates`] is reordered or modified, and copies of the
+    /// coordinate in the same plot share it. It does not follow the coordinate
+    /// into another plot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.push((1.0, 2.0));
+    /// plot.set_coordinate_style(0, "peak");
+    /// assert!(plot.to_string().contains("(1,2)\t[peak]"));
+    /// ```
+    pub fn set_coordinate_style<S: Into<String>>(&mut self, index: usize, style: S) {
+        assert!(
+            index < self.coordinates.len(),
+            "cannot style coordinate {index} of a plot with {} coordinates",
+            self.coordinates.len()
+        );
+        let key = *self.coordinates[index]
+            .style
+            .get_or_insert_with(next_style_key);
+        self.coordinate_styles.insert(key, style.into());
+    }
     /// Add a key to control the appearance of the plot. This will overwrite
     /// any previous mutually exclusive key.
     ///
@@ -145,6 +479,908 @@ impl Plot2D {
         }
         self.keys.push(key);
     }
+    /// Add a key to control the appearance of the plot, and return the plot.
+    /// This will overwrite any previous mutually exclusive key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{Plot2D, PlotKey, Type2D::SharpPlot};
+    ///
+    /// let plot = Plot2D::new()
+    ///     .with_key(PlotKey::Type2D(SharpPlot))
+    ///     .with_key(PlotKey::Custom(String::from("dashed")));
+    /// ```
+    pub fn with_key(mut self, key: PlotKey) -> Self {
+        self.add_key(key);
+        self
+    }
+    /// Translate every coordinate of the plot by `dx` and `dy`. Errors are not
+    /// modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.push((1.0, 2.0));
+    /// plot.shift(1.0, -1.0);
+    /// assert_eq!(plot.coordinates[0].x, 2.0);
+    /// assert_eq!(plot.coordinates[0].y, 1.0);
+    /// ```
+    pub fn shift(&mut self, dx: f64, dy: f64) {
+        for coordinate in self.coordinates.iter_mut() {
+            coordinate.x += dx;
+            coordinate.y += dy;
+        }
+    }
+    /// Scale every coordinate of the plot by `sx` and `sy`. Errors are scaled
+    /// by the absolute value of the factors so they remain non-negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.push((1.0, 2.0, None, Some(0.5)));
+    /// plot.scale(2.0, -2.0);
+    /// assert_eq!(plot.coordinates[0].x, 2.0);
+    /// assert_eq!(plot.coordinates[0].y, -4.0);
+    /// assert_eq!(plot.coordinates[0].error_y, Some(1.0));
+    /// ```
+    pub fn scale(&mut self, sx: f64, sy: f64) {
+        for coordinate in self.coordinates.iter_mut() {
+            coordinate.x *= sx;
+            coordinate.y *= sy;
+            coordinate.error_x = coordinate.error_x.map(|e| e * sx.abs());
+            coordinate.error_y = coordinate.error_y.map(|e| e * sy.abs());
+        }
+    }
+    /// Clamp the *y* value of every coordinate to the range from `min` to
+    /// `max`, so the plot stays inside these bounds. Coordinates with a NaN
+    /// *y* value are unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, or either bound is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.push((0.0, 5.0));
+    /// plot.clamp_y(-1.0, 1.0);
+    /// assert_eq!(plot.coordinates[0].y, 1.0);
+    /// ```
+    pub fn clamp_y(&mut self, min: f64, max: f64) {
+        for coordinate in self.coordinates.iter_mut() {
+            coordinate.y = coordinate.y.clamp(min, max);
+        }
+    }
+    /// Set the text of the entry of the plot in the legend of its [`Axis`].
+    /// This can be valid LaTeX e.g. inline math.
+    ///
+    /// The axis writes the entry with `\addlegendentry` right after the plot.
+    /// Plots without an entry are left out of the legend, and the entry of a
+    /// plot with [`PlotKey::ForgetPlot`] is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.set_legend_entry("$y = x^2$");
+    /// ```
+    pub fn set_legend_entry<S: Into<String>>(&mut self, label: S) {
+        self.legend_entry = Some(label.into());
+    }
+    /// Set the text of the entry of the plot in the legend, and return the
+    /// plot. See [`Plot2D::set_legend_entry`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot2D, Axis};
+    ///
+    /// let axis = Axis::new().with_plot(Plot2D::new().with_legend_entry("data"));
+    /// ```
+    pub fn with_legend_entry<S: Into<String>>(mut self, label: S) -> Self {
+        self.set_legend_entry(label);
+        self
+    }
+    // Text of the entry of the plot in the legend, if any.
+    pub(crate) fn legend_entry(&self) -> Option<&str> {
+        self.legend_entry.as_deref()
+    }
+    /// Reveal the plot from the given Beamer `slide` onwards in
+    /// [`Picture::to_beamer_overlays`]. Plots without an overlay are shown on
+    /// every slide. This has no effect on any other output of the plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.set_overlay(2);
+    /// ```
+    pub fn set_overlay(&mut self, slide: usize) {
+        self.overlay = Some(slide);
+    }
+    // First Beamer slide on which the plot is shown, if any.
+    pub(crate) fn overlay(&self) -> Option<usize> {
+        self.overlay
+    }
+    /// Round the values of the coordinates to the given number of decimal
+    /// `digits` when writing the plot, which keeps the generated code short.
+    /// The coordinates themselves are not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.push((1.0 / 3.0, 2.0));
+    /// plot.set_precision(2);
+    /// assert!(plot.to_string().contains("(0.33,2)"));
+    /// ```
+    pub fn set_precision(&mut self, digits: usize) {
+        self.precision = Some(digits);
+    }
+    /// Return a plot with the same keys, and coordinates at each of the
+    /// `x_values` obtained by piecewise-linear interpolation. The coordinates
+    /// of the plot must be sorted by increasing *x*. Values outside the range
+    /// of the data are clamped to the first or last *y* value (no
+    /// extrapolation). Errors and classes of the coordinates are not
+    /// interpolated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(0.0, 0.0).into(), (2.0, 4.0).into()];
+    ///
+    /// let resampled = plot.resample_linear(&[-1.0, 1.0, 3.0]);
+    /// assert_eq!(resampled.coordinates[0].y, 0.0);
+    /// assert_eq!(resampled.coordinates[1].y, 2.0);
+    /// assert_eq!(resampled.coordinates[2].y, 4.0);
+    /// ```
+    pub fn resample_linear(&self, x_values: &[f64]) -> Plot2D {
+        let coordinates = match (self.coordinates.first(), self.coordinates.last()) {
+            (Some(first), Some(last)) => x_values
+                .iter()
+                .map(|&x| {
+                    let index = self.coordinates.partition_point(|c| c.x < x);
+                    let y = if index == 0 {
+                        first.y
+                    } else if index == self.coordinates.len() {
+                        last.y
+                    } else {
+                        let (a, b) = (&self.coordinates[index - 1], &self.coordinates[index]);
+                        a.y + (x - a.x) * (b.y - a.y) / (b.x - a.x)
+                    };
+                    (x, y).into()
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        Plot2D {
+            keys: self.keys.clone(),
+            precision: self.precision,
+            coordinates,
+            ..Default::default()
+        }
+    }
+    /// Return a plot with the same keys, and the running sum of the *y*
+    /// values of the coordinates e.g. to draw a cumulative distribution. The
+    /// *x* values are unchanged. Errors, classes, and styles of the
+    /// coordinates are not kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(0.0, 1.0).into(), (1.0, 2.0).into()];
+    ///
+    /// let cumulative = plot.cumulative_y();
+    /// assert_eq!(cumulative.coordinates[0].y, 1.0);
+    /// assert_eq!(cumulative.coordinates[1].y, 3.0);
+    /// ```
+    pub fn cumulative_y(&self) -> Plot2D {
+        let mut sum = 0.0;
+        let coordinates = self
+            .coordinates
+            .iter()
+            .map(|c| {
+                sum += c.y;
+                (c.x, sum).into()
+            })
+            .collect();
+        Plot2D {
+            keys: self.keys.clone(),
+            precision: self.precision,
+            coordinates,
+            ..Default::default()
+        }
+    }
+    /// Return a plot with the same keys, and the numerical derivative of *y*
+    /// with respect to *x* at each coordinate. Central differences are used
+    /// for interior coordinates, and forward (backward) differences for the
+    /// first (last) coordinate. The coordinates of the plot must be sorted by
+    /// *x*. Consecutive coordinates with the same *x* are merged into a single
+    /// coordinate at their mean *y*, so the returned plot has one coordinate
+    /// per distinct *x*. A plot with fewer than two distinct *x* values has no
+    /// derivative, so the returned plot is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(0.0, 0.0).into(), (1.0, 1.0).into(), (2.0, 4.0).into()];
+    ///
+    /// let derivative = plot.derivative();
+    /// assert_eq!(derivative.coordinates[0].y, 1.0);
+    /// assert_eq!(derivative.coordinates[1].y, 2.0);
+    /// assert_eq!(derivative.coordinates[2].y, 3.0);
+    /// ```
+    pub fn derivative(&self) -> Plot2D {
+        // A zero step in x would divide by zero. Each point is the x value,
+        // and the sum and count of the merged y values.
+        let mut points: Vec<(f64, f64, f64)> = Vec::new();
+        for c in self.coordinates.iter() {
+            match points.last_mut() {
+                Some((x, sum, count)) if *x == c.x => {
+                    *sum += c.y;
+                    *count += 1.0;
+                }
+                _ => points.push((c.x, c.y, 1.0)),
+            }
+        }
+        let points: Vec<(f64, f64)> = points
+            .into_iter()
+            .map(|(x, sum, count)| (x, sum / count))
+            .collect();
+
+        let n = points.len();
+        let coordinates = if n < 2 {
+            Vec::new()
+        } else {
+            (0..n)
+                .map(|i| {
+                    let a = points[i.saturating_sub(1)];
+                    let b = points[(i + 1).min(n - 1)];
+                    (points[i].0, (b.1 - a.1) / (b.0 - a.0)).into()
+                })
+                .collect()
+        };
+        Plot2D {
+            keys: self.keys.clone(),
+            precision: self.precision,
+            coordinates,
+            ..Default::default()
+        }
+    }
+    /// Create a plot of triangular patches e.g. the elements of a finite
+    /// element mesh. Each three consecutive `vertices` are the corners of a
+    /// triangle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of vertices is not divisible by 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let plot = Plot2D::patch_triangles(vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)])?;
+    /// assert_eq!(plot.coordinates.len(), 3);
+    ///
+    /// assert!(Plot2D::patch_triangles(vec![(0.0, 0.0), (1.0, 0.0)]).is_err());
+    /// # Ok::<(), pgfplots::axis::plot::PatchError>(())
+    /// ```
+    pub fn patch_triangles<I, C>(vertices: I) -> Result<Plot2D, PatchError>
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Coordinate2D>,
+    {
+        let coordinates: Vec<Coordinate2D> = vertices.into_iter().map(Into::into).collect();
+        if !coordinates.len().is_multiple_of(3) {
+            return Err(PatchError {
+                vertices: coordinates.len(),
+                per_patch: 3,
+            });
+        }
+
+        let mut plot = Plot2D {
+            coordinates,
+            ..Default::default()
+        };
+        plot.add_key(PlotKey::Patch(PatchType::Triangle));
+        Ok(plot)
+    }
+    // Libraries needed to compile the plot.
+    pub(crate) fn libraries(&self) -> Vec<Library> {
+        let mut libraries = Vec::new();
+        if self.keys.iter().any(|key| matches!(key, PlotKey::Patch(_))) {
+            libraries.push(Library::PatchPlots);
+        }
+        libraries
+    }
+    // Whether compiling the plot calls an external program e.g. gnuplot.
+    pub(crate) fn requires_shell_escape(&self) -> bool {
+        keys_require_shell_escape(&self.keys)
+    }
+    // Name of the path of the plot, if any.
+    pub(crate) fn name_path(&self) -> Option<&str> {
+        self.keys.iter().find_map(|key| match key {
+            PlotKey::NamePath(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+    // Whether the plot is left out of the legend of the axis.
+    pub(crate) fn is_forgotten(&self) -> bool {
+        self.keys
+            .iter()
+            .any(|key| matches!(key, PlotKey::ForgetPlot))
+    }
+    // Error bars are drawn for coordinates with errors unless the keys say
+    // otherwise. Return the error bar keys (absolute errors in both
+    // directions) that are needed but have not been set.
+    fn implied_error_keys(&self) -> Vec<PlotKey> {
+        let has_key = |matches: fn(&PlotKey) -> bool| self.keys.iter().any(matches);
+        let mut keys = Vec::new();
+        if self.coordinates.iter().any(|c| c.error_x.is_some()) {
+            if !has_key(|k| matches!(k, PlotKey::XError(_))) {
+                keys.push(PlotKey::XError(ErrorCharacter::Absolute));
+            }
+            if !has_key(|k| matches!(k, PlotKey::XErrorDirection(_))) {
+                keys.push(PlotKey::XErrorDirection(ErrorDirection::Both));
+            }
+        }
+        if self.coordinates.iter().any(|c| c.error_y.is_some()) {
+            if !has_key(|k| matches!(k, PlotKey::YError(_))) {
+                keys.push(PlotKey::YError(ErrorCharacter::Absolute));
+            }
+            if !has_key(|k| matches!(k, PlotKey::YErrorDirection(_))) {
+                keys.push(PlotKey::YErrorDirection(ErrorDirection::Both));
+            }
+        }
+        keys
+    }
+    // Coordinates can reference a scatter class that was never given a style.
+    // PGFPlots fails to compile these, so define the missing classes with an
+    // empty (default) style.
+    fn padded_scatter_classes<'a>(&self, key: &'a PlotKey) -> Cow<'a, PlotKey> {
+        if let PlotKey::ScatterClasses(styles) = key {
+            let needed = self
+                .coordinates
+                .iter()
+                .filter_map(|c| c.class)
+                .max()
+                .map_or(0, |class| class + 1);
+            if needed > styles.len() {
+                let mut styles = styles.clone();
+                styles.resize(needed, String::new());
+                return Cow::Owned(PlotKey::ScatterClasses(styles));
+            }
+        }
+        Cow::Borrowed(key)
+    }
+    /// Label each coordinate (e.g. each bar of a bar plot) with its value,
+    /// printed in fixed-point notation with `precision` decimal digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{Plot2D, PlotKey, Type2D};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.add_key(PlotKey::Type2D(Type2D::YBar {
+    ///     bar_width: 10.0,
+    ///     bar_shift: 0.0,
+    /// }));
+    /// plot.label_bars(2);
+    /// ```
+    pub fn label_bars(&mut self, precision: usize) {
+        self.add_key(PlotKey::NodesNearCoords);
+        self.add_key(PlotKey::NodesNearCoordsStyle(format!(
+            "/pgf/number format/fixed, /pgf/number format/fixed zerofill, /pgf/number format/precision={precision}"
+        )));
+        self.add_key(PlotKey::NodesNearCoordsAlign(
+            NodesNearCoordsAlign::Vertical,
+        ));
+    }
+    /// Make both the filled areas and the lines of the plot translucent with
+    /// the same `opacity` (from 0 to 1) e.g. to overlay histograms. Any other
+    /// key, such as the type of bars, is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{Plot2D, PlotKey, Type2D};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.add_key(PlotKey::Type2D(Type2D::YBar {
+    ///     bar_width: 10.0,
+    ///     bar_shift: 0.0,
+    /// }));
+    /// plot.as_translucent(0.5);
+    /// ```
+    pub fn as_translucent(&mut self, opacity: f64) {
+        self.add_key(PlotKey::FillOpacity(opacity));
+        self.add_key(PlotKey::DrawOpacity(opacity));
+    }
+    /// Place a label with the value of each coordinate next to it, rotated by
+    /// `degrees` e.g. `90.0` to fit the labels of dense bar plots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{Plot2D, PlotKey, Type2D};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.add_key(PlotKey::Type2D(Type2D::YBar {
+    ///     bar_width: 10.0,
+    ///     bar_shift: 0.0,
+    /// }));
+    /// plot.bar_labels_rotated(90.0);
+    /// ```
+    pub fn bar_labels_rotated(&mut self, degrees: f64) {
+        self.add_key(PlotKey::NodesNearCoords);
+        self.add_key(PlotKey::EveryNodeNearCoordStyle(format!(
+            "rotate={degrees}, anchor=west"
+        )));
+    }
+    // Whether the plot is a bar plot with a non-zero bar shift.
+    pub(crate) fn is_shifted_bar(&self) -> bool {
+        self.keys.iter().any(|key| {
+            matches!(
+                key,
+                PlotKey::Type2D(Type2D::XBar { bar_shift, .. } | Type2D::YBar { bar_shift, .. })
+                    if *bar_shift != 0.0
+            )
+        })
+    }
+    // Push the warnings of the plot at index `plot` of the axis at index
+    // `axis`. The flags tell whether the axis uses logarithmic scaling.
+    pub(crate) fn validate(
+        &self,
+        axis: usize,
+        plot: usize,
+        log_x: bool,
+        log_y: bool,
+        warnings: &mut Vec<ValidationWarning>,
+    ) {
+        if self.coordinates.is_empty() {
+            warnings.push(ValidationWarning::EmptyPlot { axis, plot });
+        }
+        for (coordinate, c) in self.coordinates.iter().enumerate() {
+            if (log_x && c.x <= 0.0) || (log_y && c.y <= 0.0) {
+                warnings.push(ValidationWarning::NonPositiveLogCoordinate {
+                    axis,
+                    plot,
+                    coordinate,
+                });
+            }
+        }
+        let zero_width = self.keys.iter().any(|key| {
+            matches!(
+                key,
+                PlotKey::Type2D(Type2D::XBar { bar_width, .. } | Type2D::YBar { bar_width, .. })
+                    if *bar_width == 0.0
+            )
+        });
+        if zero_width {
+            warnings.push(ValidationWarning::ZeroBarWidth { axis, plot });
+        }
+    }
+}
+
+/// The error type returned when parsing a [`Plot2D`] fails.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("invalid coordinate in line {line}: {source}")]
+pub struct ParsePlotError {
+    /// Line number (starting at 1) of the invalid coordinate.
+    pub line: usize,
+    /// Reason why the coordinate is invalid.
+    pub source: ParseCoordinateError,
+}
+
+/// The error type returned when the vertices of a patch plot cannot be grouped
+/// into patches.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("{vertices} vertices cannot be grouped into patches of {per_patch} vertices")]
+pub struct PatchError {
+    /// Number of vertices given.
+    pub vertices: usize,
+    /// Number of vertices of each patch.
+    pub per_patch: usize,
+}
+
+/// The error type returned when reading a [`Plot2D`] from comma-separated
+/// values fails.
+#[cfg(feature = "csv")]
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum CsvError {
+    /// Failed to read the values.
+    #[error("failed to read comma-separated values: {0}")]
+    Io(#[from] std::io::Error),
+    /// A row has fewer columns than needed.
+    #[error("row {row} has no column {column}")]
+    MissingColumn {
+        /// Row number (starting at 1, including the header).
+        row: usize,
+        /// Index (starting at 0) of the missing column.
+        column: usize,
+    },
+    /// A field is not a number.
+    #[error("invalid number in row {row}, column {column}: {source}")]
+    InvalidNumber {
+        /// Row number (starting at 1, including the header).
+        row: usize,
+        /// Index (starting at 0) of the column of the field.
+        column: usize,
+        /// Reason why the field is not a number.
+        source: std::num::ParseFloatError,
+    },
+}
+
+impl FromStr for Plot2D {
+    type Err = ParsePlotError;
+    /// Parse a two-dimensional plot from text with one `x y` coordinate per
+    /// line. Blank lines and comments (starting with `#`) are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let plot: Plot2D = "# x y
+    /// 0 0
+    /// 1 1
+    ///
+    /// 2 4 # last point"
+    ///     .parse()
+    ///     .unwrap();
+    /// assert_eq!(plot.coordinates.len(), 3);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut plot = Plot2D::new();
+        for (index, line) in s.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let coordinate = line.parse().map_err(|source| ParsePlotError {
+                line: index + 1,
+                source,
+            })?;
+            plot.coordinates.push(coordinate);
+        }
+        Ok(plot)
+    }
+}
+
+impl<C: Into<Coordinate2D>> FromIterator<C> for Plot2D {
+    /// Create a plot with the collected coordinates, and the same keys as
+    /// [`Plot2D::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let plot: Plot2D = (0..5).map(|i| (f64::from(i), f64::from(i * i))).collect();
+    /// assert_eq!(plot.coordinates.len(), 5);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = C>>(iter: I) -> Self {
+        let mut plot = Plot2D::new();
+        plot.extend(iter);
+        plot
+    }
+}
+
+impl<C: Into<Coordinate2D>> Extend<C> for Plot2D {
+    /// Append the coordinates to the end of the plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.extend([(0.0, 1.0), (1.0, 2.0)]);
+    /// assert_eq!(plot.coordinates.len(), 2);
+    /// ```
+    fn extend<I: IntoIterator<Item = C>>(&mut self, iter: I) {
+        self.coordinates.extend(iter.into_iter().map(Into::into));
+    }
+}
+
+/// Sort the finite `values` into `bins` bins of equal width spanning their
+/// range, and return the center and number of values of each bin e.g. to draw
+/// a histogram with a [`Type2D::YBar`] plot. If every value is the
+/// same, there is a single bin centered at that value. There are no bins if
+/// `bins` is zero or there are no finite values.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::plot::{bin_counts, Plot2D};
+///
+/// let counts = bin_counts(&[0.0, 1.0, 2.0, 3.0], 2);
+/// assert_eq!(counts, vec![(0.75, 2.0), (2.25, 2.0)]);
+///
+/// let mut plot = Plot2D::new();
+/// plot.coordinates = counts.into_iter().map(Into::into).collect();
+/// ```
+pub fn bin_counts(values: &[f64], bins: usize) -> Vec<(f64, f64)> {
+    let values: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    let (min, max) = values
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+            (min.min(v), max.max(v))
+        });
+    if bins == 0 || values.is_empty() {
+        return Vec::new();
+    }
+    if min == max {
+        return vec![(min, values.len() as f64)];
+    }
+
+    let width = (max - min) / bins as f64;
+    let mut counts = vec![0.0; bins];
+    for value in values {
+        // The maximum value belongs to the last bin.
+        let bin = (((value - min) / width) as usize).min(bins - 1);
+        counts[bin] += 1.0;
+    }
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(bin, count)| (min + (bin as f64 + 0.5) * width, count))
+        .collect()
+}
+
+/// Three-dimensional plot inside an [`Axis`].
+///
+/// Adding a [`Plot3D`] to an [`Axis`] environment is equivalent to:
+///
+/// ```text
+/// \addplot3[PlotKeys]
+///     % coordinates;
+/// ```
+///
+/// Surfaces and meshes connect the coordinates row by row, so set
+/// [`PlotKey::MeshRows`] when drawing them.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::plot::{Plot3D, PlotKey, Type3D};
+///
+/// let mut plot = Plot3D::new();
+/// for x in 0..10 {
+///     for y in 0..10 {
+///         plot.push((x as f64, y as f64, (x * y) as f64));
+///     }
+/// }
+/// plot.add_key(PlotKey::Type3D(Type3D::Surf));
+/// plot.add_key(PlotKey::MeshRows(10));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Plot3D {
+    keys: Vec<PlotKey>,
+    legend_entry: Option<String>,
+    pub coordinates: Vec<Coordinate3D>,
+}
+
+impl fmt::Display for Plot3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_comments(f, &self.keys)?;
+        write!(f, "\t\\addplot3[")?;
+        // If there are keys, print them one per line. It makes it easier for a
+        // human to find individual keys later.
+        let implied_legend_key = self.implied_legend_key();
+        let keys: Vec<&PlotKey> = self
+            .keys
+            .iter()
+            .filter(|key| !matches!(key, PlotKey::Comment(_)))
+            .chain(implied_legend_key.as_ref())
+            .collect();
+        if !keys.is_empty() {
+            writeln!(f)?;
+            for key in keys {
+                writeln!(f, "\t\t{key},")?;
+            }
+            write!(f, "\t")?;
+        }
+        writeln!(f, "] coordinates {{")?;
+
+        for coordinate in self.coordinates.iter() {
+            writeln!(f, "\t\t{coordinate}")?;
+        }
+
+        write!(f, "\t}};")?;
+
+        Ok(())
+    }
+}
+
+impl Plot3D {
+    /// Creates a new, empty three-dimensional plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot3D;
+    ///
+    /// let mut plot = Plot3D::new();
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Append a coordinate to the end of the plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot3D;
+    ///
+    /// let mut plot = Plot3D::new();
+    /// plot.push((1.0, 2.0, 3.0));
+    /// assert_eq!(plot.coordinates.len(), 1);
+    /// ```
+    pub fn push<C: Into<Coordinate3D>>(&mut self, coordinate: C) {
+        self.coordinates.push(coordinate.into());
+    }
+    /// Add a key to control the appearance of the plot. This will overwrite
+    /// any previous mutually exclusive key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{Plot3D, PlotKey, Type3D};
+    ///
+    /// let mut plot = Plot3D::new();
+    /// plot.add_key(PlotKey::Type3D(Type3D::Mesh));
+    /// ```
+    pub fn add_key(&mut self, key: PlotKey) {
+        match key {
+            PlotKey::Custom(_) => (),
+            _ => {
+                if let Some(index) = self
+                    .keys
+                    .iter()
+                    .position(|k| std::mem::discriminant(k) == std::mem::discriminant(&key))
+                {
+                    self.keys.remove(index);
+                }
+            }
+        }
+        self.keys.push(key);
+    }
+    /// Add a key to control the appearance of the plot, and return the plot.
+    /// This will overwrite any previous mutually exclusive key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{Plot3D, PlotKey, Type3D};
+    ///
+    /// let plot = Plot3D::new()
+    ///     .with_key(PlotKey::Type3D(Type3D::Surf))
+    ///     .with_key(PlotKey::MeshRows(10));
+    /// ```
+    pub fn with_key(mut self, key: PlotKey) -> Self {
+        self.add_key(key);
+        self
+    }
+    /// Set the text of the entry of the plot in the legend of its
+    /// [`Axis`](crate::axis::Axis). This can be valid LaTeX e.g. inline math.
+    ///
+    /// The axis writes the entry with `\addlegendentry` right after the plot.
+    /// The default legend image of PGFPlots is a line, so surfaces and meshes
+    /// with an entry get [`PlotKey::AreaLegend`] unless the plot already has
+    /// it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{Plot3D, PlotKey, Type3D};
+    ///
+    /// let mut plot = Plot3D::new().with_key(PlotKey::Type3D(Type3D::Mesh));
+    /// plot.set_legend_entry("$z = x y$");
+    /// assert!(plot.to_string().contains("\t\tarea legend,\n"));
+    /// ```
+    pub fn set_legend_entry<S: Into<String>>(&mut self, label: S) {
+        self.legend_entry = Some(label.into());
+    }
+    /// Set the text of the entry of the plot in the legend, and return the
+    /// plot. See [`Plot3D::set_legend_entry`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot3D, Axis};
+    ///
+    /// let axis = Axis::new().with_plot_3d(Plot3D::new().with_legend_entry("data"));
+    /// ```
+    pub fn with_legend_entry<S: Into<String>>(mut self, label: S) -> Self {
+        self.set_legend_entry(label);
+        self
+    }
+    // Text of the entry of the plot in the legend, if any.
+    pub(crate) fn legend_entry(&self) -> Option<&str> {
+        self.legend_entry.as_deref()
+    }
+    // Whether the plot is left out of the legend.
+    pub(crate) fn is_forgotten(&self) -> bool {
+        self.keys
+            .iter()
+            .any(|key| matches!(key, PlotKey::ForgetPlot))
+    }
+    // Surfaces and meshes in the legend are shown as an area instead of the
+    // default line. Return the legend key that is needed but has not been set.
+    fn implied_legend_key(&self) -> Option<PlotKey> {
+        let is_area = self
+            .keys
+            .iter()
+            .any(|key| matches!(key, PlotKey::Type3D(Type3D::Surf | Type3D::Mesh)));
+        let has_key = self
+            .keys
+            .iter()
+            .any(|key| matches!(key, PlotKey::AreaLegend));
+        (is_area && !has_key && self.legend_entry.is_some() && !self.is_forgotten())
+            .then_some(PlotKey::AreaLegend)
+    }
+    // Whether compiling the plot calls an external program e.g. gnuplot.
+    pub(crate) fn requires_shell_escape(&self) -> bool {
+        keys_require_shell_escape(&self.keys)
+    }
+}
+
+// Write the comment keys, one line each, before the `\addplot` command.
+fn write_comments(f: &mut fmt::Formatter<'_>, keys: &[PlotKey]) -> fmt::Result {
+    for key in keys.iter() {
+        if let PlotKey::Comment(comment) = key {
+            for line in comment.lines() {
+                writeln!(f, "\t% {line}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Return a key for the style of a coordinate that no other coordinate has. The
+// keys are unique across plots, so a coordinate copied from another plot does
+// not pick up a style of this one.
+fn next_style_key() -> usize {
+    static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+    COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+}
+
+// PGFPlots hands gnuplot based keys (e.g. `contour gnuplot`, `raw gnuplot`)
+// to gnuplot, which can only be run if shell escape is enabled. No typed key
+// uses gnuplot, so only custom keys are checked.
+fn keys_require_shell_escape(keys: &[PlotKey]) -> bool {
+    keys.iter()
+        .any(|key| matches!(key, PlotKey::Custom(key) if key.contains("gnuplot")))
 }
 
 /// Control the type of two dimensional plots.
@@ -276,5 +1512,215 @@ impl fmt::Display for ErrorDirection {
     }
 }
 
+/// Control how coordinates with non-finite values are handled.
+#[derive(Clone, Copy, Debug)]
+pub enum UnboundedCoords {
+    /// Skip non-finite coordinates and connect their neighbors, bridging the
+    /// gap.
+    Discard,
+    /// Interrupt the plot at non-finite coordinates, leaving a gap.
+    Jump,
+}
+impl fmt::Display for UnboundedCoords {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnboundedCoords::Discard => write!(f, "discard"),
+            UnboundedCoords::Jump => write!(f, "jump"),
+        }
+    }
+}
+
+/// Control how the corners of a line are drawn.
+#[derive(Clone, Copy, Debug)]
+pub enum LineJoin {
+    /// Round corners.
+    Round,
+    /// Sharp corners, the default.
+    Miter,
+    /// Corners cut off by a straight line.
+    Bevel,
+}
+impl fmt::Display for LineJoin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineJoin::Round => write!(f, "round"),
+            LineJoin::Miter => write!(f, "miter"),
+            LineJoin::Bevel => write!(f, "bevel"),
+        }
+    }
+}
+
+/// Control how the end points of a line are drawn.
+#[derive(Clone, Copy, Debug)]
+pub enum LineCap {
+    /// End points are rounded, extending beyond the end of the line.
+    Round,
+    /// End points are squared, extending beyond the end of the line.
+    Rect,
+    /// End points are squared exactly at the end of the line, the default.
+    Butt,
+}
+impl fmt::Display for LineCap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineCap::Round => write!(f, "round"),
+            LineCap::Rect => write!(f, "rect"),
+            LineCap::Butt => write!(f, "butt"),
+        }
+    }
+}
+
+/// Control the dash pattern of a line.
+#[derive(Clone, Copy, Debug)]
+pub enum LineStyle {
+    /// Continuous line, the default.
+    Solid,
+    /// Line made of dashes.
+    Dashed,
+    /// Line made of dots.
+    Dotted,
+    /// Line made of alternating dashes and dots.
+    DashDot,
+}
+impl fmt::Display for LineStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineStyle::Solid => write!(f, "solid"),
+            LineStyle::Dashed => write!(f, "dashed"),
+            LineStyle::Dotted => write!(f, "dotted"),
+            LineStyle::DashDot => write!(f, "dash dot"),
+        }
+    }
+}
+
+/// Control the width of a line.
+#[derive(Clone, Copy, Debug)]
+pub enum LineWidth {
+    /// Thin line (`0.4pt`), the default.
+    Thin,
+    /// Thick line (`0.8pt`).
+    Thick,
+    /// Very thick line (`1.2pt`).
+    VeryThick,
+    /// Line with a width in `pt`.
+    Pt(f64),
+}
+impl fmt::Display for LineWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineWidth::Thin => write!(f, "thin"),
+            LineWidth::Thick => write!(f, "thick"),
+            LineWidth::VeryThick => write!(f, "very thick"),
+            LineWidth::Pt(value) => write!(f, "line width={value}pt"),
+        }
+    }
+}
+
+/// Control the alignment of nodes near coordinates.
+#[derive(Clone, Copy, Debug)]
+pub enum NodesNearCoordsAlign {
+    /// Place nodes above (or below, for negative values) each coordinate.
+    Vertical,
+    /// Place nodes to the right (or left, for negative values) of each
+    /// coordinate.
+    Horizontal,
+}
+impl fmt::Display for NodesNearCoordsAlign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodesNearCoordsAlign::Vertical => write!(f, "vertical"),
+            NodesNearCoordsAlign::Horizontal => write!(f, "horizontal"),
+        }
+    }
+}
+
+/// Shape of the patches drawn by [`PlotKey::Patch`].
+#[derive(Clone, Copy, Debug)]
+pub enum PatchType {
+    /// Quadrilateral with four vertices.
+    Rectangle,
+    /// Triangle with three vertices.
+    Triangle,
+    /// Line segment with two vertices.
+    Line,
+}
+impl fmt::Display for PatchType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatchType::Rectangle => write!(f, "rectangle"),
+            PatchType::Triangle => write!(f, "triangle"),
+            PatchType::Line => write!(f, "line"),
+        }
+    }
+}
+
+/// Control the type of three dimensional plots.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum Type3D {
+    /// Draw a filled surface through the coordinates, colored by their *z*
+    /// value. Set [`PlotKey::MeshRows`] to tell how the coordinates are
+    /// connected.
+    Surf,
+    /// Draw a wire-frame mesh through the coordinates, colored by their *z*
+    /// value. Set [`PlotKey::MeshRows`] to tell how the coordinates are
+    /// connected.
+    Mesh,
+    /// Draw only markers at the coordinates, colored by their *z* value.
+    Scatter,
+}
+impl fmt::Display for Type3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type3D::Surf => write!(f, "surf"),
+            Type3D::Mesh => write!(f, "mesh"),
+            Type3D::Scatter => write!(f, "only marks, scatter"),
+        }
+    }
+}
+
+/// Shape of the markers of a plot.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Mark {
+    /// Do not draw markers.
+    None,
+    /// Filled circle.
+    Dot,
+    /// Circle outline.
+    Circle,
+    /// Cross shaped like an *x*.
+    X,
+    /// Cross shaped like a plus sign.
+    Plus,
+    /// Five-pointed star.
+    Star,
+    /// Filled square.
+    Square,
+    /// Filled triangle.
+    Triangle,
+    /// Filled diamond.
+    Diamond,
+    /// Custom marker e.g. `pentagon*` or `text`. This will be written
+    /// verbatim.
+    Custom(String),
+}
+impl fmt::Display for Mark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mark::None => write!(f, "none"),
+            Mark::Dot => write!(f, "*"),
+            Mark::Circle => write!(f, "o"),
+            Mark::X => write!(f, "x"),
+            Mark::Plus => write!(f, "+"),
+            Mark::Star => write!(f, "star"),
+            Mark::Square => write!(f, "square*"),
+            Mark::Triangle => write!(f, "triangle*"),
+            Mark::Diamond => write!(f, "diamond*"),
+            Mark::Custom(value) => write!(f, "{value}"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
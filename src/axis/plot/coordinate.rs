@@ -1,9 +1,12 @@
 use std::fmt;
+use std::num::ParseFloatError;
+use std::str::FromStr;
+use thiserror::Error;
 
 // Only imported for documentation. If you notice this is no longer the case,
 // please change it.
 #[allow(unused_imports)]
-use crate::axis::plot::{Plot2D, PlotKey};
+use crate::axis::plot::{Plot2D, Plot3D, PlotKey};
 
 /// Coordinate in a two-dimensional plot.
 #[derive(Clone, Copy, Debug)]
@@ -11,17 +14,26 @@ use crate::axis::plot::{Plot2D, PlotKey};
 pub struct Coordinate2D {
     pub x: f64,
     pub y: f64,
-    /// By default, error bars are not drawn (even if it is a [`Some`]). These
-    /// are only drawn if both [`PlotKey::XError`] and
-    /// [`PlotKey::XErrorDirection`] are set in the [`Plot2D`].
+    /// Error of the *x* value. If any coordinate of a [`Plot2D`] has one, error
+    /// bars are drawn in both directions unless [`PlotKey::XError`] or
+    /// [`PlotKey::XErrorDirection`] say otherwise.
     pub error_x: Option<f64>,
-    /// By default, error bars are not drawn (even if it is a [`Some`]). These
-    /// are only drawn if both [`PlotKey::YError`] and
-    /// [`PlotKey::YErrorDirection`] are set in the [`Plot2D`].
+    /// Error of the *y* value. If any coordinate of a [`Plot2D`] has one, error
+    /// bars are drawn in both directions unless [`PlotKey::YError`] or
+    /// [`PlotKey::YErrorDirection`] say otherwise.
     pub error_y: Option<f64>,
-    // What to do when `point meta=explicit` in plot?
-    // Should we add an Option<point_meta> here?
-    // Is `point meta` skipped same as error when it is not set?
+    /// Index of the scatter class of the coordinate. This is ignored unless
+    /// [`PlotKey::ScatterClasses`] is set in the [`Plot2D`].
+    pub class: Option<usize>,
+    /// Point meta value of the coordinate e.g. to color it with the colormap
+    /// of the axis. This is ignored unless [`PlotKey::PointMetaColor`] is set
+    /// in the [`Plot2D`]. It is written in the same place as the
+    /// [`Coordinate2D::class`], which takes precedence.
+    pub meta: Option<f64>,
+    // Key of the style written after the coordinate by the plot that set it,
+    // see Plot2D::set_coordinate_style. Keys are unique across plots, so the
+    // style does not follow the coordinate into another plot.
+    pub(crate) style: Option<usize>,
 }
 
 impl fmt::Display for Coordinate2D {
@@ -34,10 +46,114 @@ impl fmt::Display for Coordinate2D {
             write!(f, "\t+- ({error_x},{error_y})")?;
         }
 
+        if let Some(class) = self.class {
+            write!(f, "\t[{class}]")?;
+        } else if let Some(meta) = self.meta {
+            write!(f, "\t[{meta}]")?;
+        }
+
         Ok(())
     }
 }
 
+impl Coordinate2D {
+    /// Create a coordinate with an error of its *x* value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::coordinate::Coordinate2D;
+    ///
+    /// let point = Coordinate2D::with_x_error(1.0, -1.0, 0.5);
+    ///
+    /// assert_eq!(point.error_x, Some(0.5));
+    /// assert!(point.error_y.is_none());
+    /// ```
+    pub fn with_x_error(x: f64, y: f64, error: f64) -> Self {
+        (x, y, Some(error), None).into()
+    }
+    /// Create a coordinate with an error of its *y* value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::coordinate::Coordinate2D;
+    ///
+    /// let point = Coordinate2D::with_y_error(1.0, -1.0, 0.5);
+    ///
+    /// assert!(point.error_x.is_none());
+    /// assert_eq!(point.error_y, Some(0.5));
+    /// ```
+    pub fn with_y_error(x: f64, y: f64, error: f64) -> Self {
+        (x, y, None, Some(error)).into()
+    }
+    /// Create a coordinate with a point meta value e.g. to color it in a
+    /// scatter plot with [`PlotKey::PointMetaColor`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::coordinate::Coordinate2D;
+    ///
+    /// let point = Coordinate2D::with_meta(1.0, -1.0, 0.5);
+    ///
+    /// assert_eq!(point.meta, Some(0.5));
+    /// assert_eq!(point.to_string(), "(1,-1)\t[0.5]");
+    /// ```
+    pub fn with_meta(x: f64, y: f64, meta: f64) -> Self {
+        let mut coordinate: Coordinate2D = (x, y).into();
+        coordinate.meta = Some(meta);
+        coordinate
+    }
+    /// Return the coordinate as a tab-separated row of a PGFPlots `table`.
+    /// The columns are *x* and *y*, then both errors if the coordinate has
+    /// any (a missing error is written as 0), then the class or the meta
+    /// value if it has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::coordinate::Coordinate2D;
+    ///
+    /// let coordinate = Coordinate2D::with_y_error(1.0, 2.0, 0.5);
+    /// assert_eq!(coordinate.to_table_row(), "1\t2\t0\t0.5");
+    /// ```
+    pub fn to_table_row(&self) -> String {
+        let mut row = format!("{}\t{}", self.x, self.y);
+        if self.error_x.is_some() || self.error_y.is_some() {
+            let error_x = self.error_x.unwrap_or(0.0);
+            let error_y = self.error_y.unwrap_or(0.0);
+            row.push_str(&format!("\t{error_x}\t{error_y}"));
+        }
+        if let Some(class) = self.class {
+            row.push_str(&format!("\t{class}"));
+        } else if let Some(meta) = self.meta {
+            row.push_str(&format!("\t{meta}"));
+        }
+        row
+    }
+    // Return the coordinate with its values (and errors) rounded to the given
+    // number of decimal digits.
+    pub(crate) fn rounded(&self, digits: usize) -> Coordinate2D {
+        // Going through the decimal representation avoids values such as
+        // 1.2300000000000002 that result from scaling by powers of ten. Adding
+        // zero turns a rounded -0 into 0.
+        let round = |value: f64| {
+            format!("{value:.digits$}")
+                .parse::<f64>()
+                .expect("formatted float should parse")
+                + 0.0
+        };
+        Coordinate2D {
+            x: round(self.x),
+            y: round(self.y),
+            error_x: self.error_x.map(round),
+            error_y: self.error_y.map(round),
+            ..*self
+        }
+    }
+}
+
 impl From<(f64, f64)> for Coordinate2D {
     /// Conversion from an `(x,y)` tuple into a two-dimensional coordinate.
     ///
@@ -59,6 +175,9 @@ impl From<(f64, f64)> for Coordinate2D {
             y: coordinate.1,
             error_x: None,
             error_y: None,
+            class: None,
+            meta: None,
+            style: None,
         }
     }
 }
@@ -85,9 +204,106 @@ impl From<(f64, f64, Option<f64>, Option<f64>)> for Coordinate2D {
             y: coordinate.1,
             error_x: coordinate.2,
             error_y: coordinate.3,
+            class: None,
+            meta: None,
+            style: None,
         }
     }
 }
 
+/// Coordinate in a three-dimensional plot.
+///
+/// A [`f64::NAN`] value is a hole e.g. in a surface. These are only skipped
+/// if [`PlotKey::UnboundedCoords`] is set in the [`Plot3D`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct Coordinate3D {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl fmt::Display for Coordinate3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{},{})", self.x, self.y, self.z)
+    }
+}
+
+impl From<(f64, f64, f64)> for Coordinate3D {
+    /// Conversion from an `(x,y,z)` tuple into a three-dimensional coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::coordinate::Coordinate3D;
+    ///
+    /// let point: Coordinate3D = (1.0, -1.0, 2.0).into();
+    ///
+    /// assert_eq!(point.x, 1.0);
+    /// assert_eq!(point.y, -1.0);
+    /// assert_eq!(point.z, 2.0);
+    /// ```
+    fn from(coordinate: (f64, f64, f64)) -> Self {
+        Coordinate3D {
+            x: coordinate.0,
+            y: coordinate.1,
+            z: coordinate.2,
+        }
+    }
+}
+
+/// The error type returned when parsing a [`Coordinate2D`] fails.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ParseCoordinateError {
+    /// Opening and closing parentheses don't match.
+    #[error("unbalanced parentheses")]
+    UnbalancedParentheses,
+    /// The string doesn't contain exactly two values.
+    #[error("expected 2 values, found {0}")]
+    InvalidLength(usize),
+    /// One of the values is not a valid number.
+    #[error("invalid number: {0}")]
+    InvalidNumber(#[from] ParseFloatError),
+}
+
+impl FromStr for Coordinate2D {
+    type Err = ParseCoordinateError;
+    /// Parse a two-dimensional coordinate from an `x` and `y` value separated
+    /// by a comma or white space, optionally enclosed in parentheses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::coordinate::Coordinate2D;
+    ///
+    /// let point: Coordinate2D = "(1, -2.5)".parse().unwrap();
+    /// assert_eq!(point.x, 1.0);
+    /// assert_eq!(point.y, -2.5);
+    ///
+    /// let point: Coordinate2D = "3 4".parse().unwrap();
+    /// assert_eq!(point.x, 3.0);
+    /// assert_eq!(point.y, 4.0);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = match (s.strip_prefix('('), s.ends_with(')')) {
+            (Some(inner), true) => inner.strip_suffix(')').unwrap(),
+            (None, false) => s,
+            _ => return Err(ParseCoordinateError::UnbalancedParentheses),
+        };
+
+        let values: Vec<&str> = if s.contains(',') {
+            s.split(',').map(str::trim).collect()
+        } else {
+            s.split_whitespace().collect()
+        };
+        if values.len() != 2 {
+            return Err(ParseCoordinateError::InvalidLength(values.len()));
+        }
+
+        Ok((values[0].parse()?, values[1].parse()?).into())
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -7,6 +7,8 @@ fn coordinate_2d_from_short_tuple() {
     assert_eq!(coord.y, -1.0);
     assert!(coord.error_x.is_none());
     assert!(coord.error_y.is_none());
+    assert!(coord.class.is_none());
+    assert!(coord.meta.is_none());
 }
 
 #[test]
@@ -49,4 +51,114 @@ fn coordinate_2d_to_string() {
 
     let coord: Coordinate2D = (1.0, -1.0, Some(4.0), Some(3.0)).into();
     assert_eq!(coord.to_string(), "(1,-1)\t+- (4,3)");
+
+    let mut coord: Coordinate2D = (1.0, -1.0).into();
+    coord.class = Some(2);
+    assert_eq!(coord.to_string(), "(1,-1)\t[2]");
+
+    coord.error_y = Some(3.0);
+    assert_eq!(coord.to_string(), "(1,-1)\t+- (0,3)\t[2]");
+}
+
+#[test]
+fn coordinate_2d_with_meta_to_string() {
+    let mut coord = Coordinate2D::with_meta(1.0, -1.0, 0.25);
+    assert_eq!(coord.meta, Some(0.25));
+    assert_eq!(coord.to_string(), "(1,-1)\t[0.25]");
+    assert_eq!(coord.to_table_row(), String::from("1\t-1\t0.25"));
+
+    coord.class = Some(1);
+    assert_eq!(coord.to_string(), "(1,-1)\t[1]");
+    assert_eq!(coord.to_table_row(), String::from("1\t-1\t1"));
+}
+
+#[test]
+fn coordinate_2d_to_table_row() {
+    let mut coordinate: Coordinate2D = (1.5, -2.0).into();
+    assert_eq!(coordinate.to_table_row(), String::from("1.5\t-2"));
+
+    coordinate.class = Some(3);
+    assert_eq!(coordinate.to_table_row(), String::from("1.5\t-2\t3"));
+    assert_eq!(coordinate.to_table_row().split('\t').count(), 3);
+
+    coordinate.error_x = Some(0.1);
+    assert_eq!(
+        coordinate.to_table_row(),
+        String::from("1.5\t-2\t0.1\t0\t3")
+    );
+}
+
+#[test]
+fn coordinate_2d_from_str() {
+    for s in ["1.0 2.0", "(1, 2)", "1,2", " ( 1\t2 ) ", "(1.0,2.0)"] {
+        let coord: Coordinate2D = s.parse().unwrap();
+        assert_eq!(coord.x, 1.0);
+        assert_eq!(coord.y, 2.0);
+        assert!(coord.error_x.is_none());
+        assert!(coord.error_y.is_none());
+        assert!(coord.class.is_none());
+    }
+
+    let coord: Coordinate2D = "-1e3 2.5".parse().unwrap();
+    assert_eq!(coord.x, -1000.0);
+    assert_eq!(coord.y, 2.5);
+}
+
+#[test]
+fn coordinate_2d_from_str_invalid() {
+    assert_eq!(
+        "(1, 2".parse::<Coordinate2D>().unwrap_err(),
+        ParseCoordinateError::UnbalancedParentheses
+    );
+    assert_eq!(
+        "1, 2)".parse::<Coordinate2D>().unwrap_err(),
+        ParseCoordinateError::UnbalancedParentheses
+    );
+    assert_eq!(
+        "1 2 3".parse::<Coordinate2D>().unwrap_err(),
+        ParseCoordinateError::InvalidLength(3)
+    );
+    assert_eq!(
+        "".parse::<Coordinate2D>().unwrap_err(),
+        ParseCoordinateError::InvalidLength(0)
+    );
+    assert!(matches!(
+        "1, a".parse::<Coordinate2D>().unwrap_err(),
+        ParseCoordinateError::InvalidNumber(_)
+    ));
+    assert_eq!(
+        "1,,2".parse::<Coordinate2D>().unwrap_err(),
+        ParseCoordinateError::InvalidLength(3)
+    );
+}
+
+#[test]
+fn coordinate_3d_from_tuple() {
+    let coord: Coordinate3D = (1.0, -1.0, 0.5).into();
+    assert_eq!(coord.x, 1.0);
+    assert_eq!(coord.y, -1.0);
+    assert_eq!(coord.z, 0.5);
+}
+
+#[test]
+fn coordinate_3d_to_string() {
+    let coord: Coordinate3D = (1.0, -1.0, 0.5).into();
+    assert_eq!(coord.to_string(), "(1,-1,0.5)");
+
+    let coord: Coordinate3D = (1.0, 2.0, f64::NAN).into();
+    assert_eq!(coord.to_string(), "(1,2,NaN)");
+}
+
+#[test]
+fn coordinate_2d_with_error() {
+    let coord = Coordinate2D::with_x_error(1.0, -1.0, 0.5);
+    assert_eq!(coord.x, 1.0);
+    assert_eq!(coord.y, -1.0);
+    assert_eq!(coord.error_x, Some(0.5));
+    assert!(coord.error_y.is_none());
+
+    let coord = Coordinate2D::with_y_error(1.0, -1.0, 0.5);
+    assert!(coord.error_x.is_none());
+    assert_eq!(coord.error_y, Some(0.5));
+    assert_eq!(coord.to_string(), "(1,-1)\t+- (0,0.5)");
 }
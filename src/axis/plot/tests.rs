@@ -1,4 +1,5 @@
 use super::*;
+use crate::color::Color;
 
 #[test]
 fn error_direction_to_string() {
@@ -53,6 +54,33 @@ fn plot_type2d_tested() {
     }
 }
 
+// This test is here only to let us know if we added an enum variant
+// but we forgot to add unit tests for it
+//
+// If this fails, it is because you added a new variant.
+// Please do the following:
+// 1) Add a unit test for the new variant you added (see examples below).
+// 2) AFTER doing (1), add the new variant to the match.
+#[test]
+fn plot_type3d_tested() {
+    let type_3d = Type3D::Surf;
+    match type_3d {
+        Type3D::Surf => (),
+        Type3D::Mesh => (),
+        Type3D::Scatter => (),
+    }
+}
+
+#[test]
+fn type_3d_to_string() {
+    assert_eq!(Type3D::Surf.to_string(), String::from("surf"));
+    assert_eq!(Type3D::Mesh.to_string(), String::from("mesh"));
+    assert_eq!(
+        Type3D::Scatter.to_string(),
+        String::from("only marks, scatter")
+    );
+}
+
 #[test]
 fn type_2d_to_string() {
     assert_eq!(Type2D::SharpPlot.to_string(), String::from("sharp plot"));
@@ -132,9 +160,388 @@ fn plot_keys_tested() {
         PlotKey::XErrorDirection(_) => (),
         PlotKey::YError(_) => (),
         PlotKey::YErrorDirection(_) => (),
+        PlotKey::YErrorPlus(_) => (),
+        PlotKey::YErrorMinus(_) => (),
+        PlotKey::NodesNearCoords => (),
+        PlotKey::NodesNearCoordsAlign(_) => (),
+        PlotKey::NodesNearCoordsStyle(_) => (),
+        PlotKey::ScatterClasses(_) => (),
+        PlotKey::UnboundedCoords(_) => (),
+        PlotKey::LineJoin(_) => (),
+        PlotKey::LineCap(_) => (),
+        PlotKey::MarkIndices(_) => (),
+        PlotKey::Patch(_) => (),
+        PlotKey::EveryNodeNearCoordStyle(_) => (),
+        PlotKey::Type3D(_) => (),
+        PlotKey::MeshRows(_) => (),
+        PlotKey::FillOpacity(_) => (),
+        PlotKey::DrawOpacity(_) => (),
+        PlotKey::AreaLegend => (),
+        PlotKey::Mark(_) => (),
+        PlotKey::MarkSize(_) => (),
+        PlotKey::LineStyle(_) => (),
+        PlotKey::LineWidth(_) => (),
+        PlotKey::Color(_) => (),
+        PlotKey::ForgetPlot => (),
+        PlotKey::NamePath(_) => (),
+        PlotKey::Comment(_) => (),
+        PlotKey::ColorbarSource => (),
+        PlotKey::PointMetaColor => (),
+        PlotKey::Samples(_) => (),
+        PlotKey::SamplesY(_) => (),
     }
 }
 
+#[test]
+fn plot_key_samples_to_string() {
+    assert_eq!(PlotKey::Samples(25).to_string(), String::from("samples=25"));
+}
+
+#[test]
+fn plot_key_samples_y_to_string() {
+    assert_eq!(
+        PlotKey::SamplesY(10).to_string(),
+        String::from("samples y=10")
+    );
+}
+
+#[test]
+fn plot_key_point_meta_color_to_string() {
+    assert_eq!(
+        PlotKey::PointMetaColor.to_string(),
+        String::from("scatter, scatter src=explicit")
+    );
+}
+
+#[test]
+fn plot_2d_point_meta_color_to_string() {
+    let mut plot = Plot2D::new().with_key(PlotKey::PointMetaColor);
+    plot.push(Coordinate2D::with_meta(1.0, 2.0, 0.5));
+    plot.push((3.0, 4.0));
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[\n\t\tscatter, scatter src=explicit,\n\t] coordinates {\n\t\t(1,2)\t[0.5]\n\t\t(3,4)\n\t};"
+    );
+}
+
+#[test]
+fn plot_key_colorbar_source_to_string() {
+    assert_eq!(
+        PlotKey::ColorbarSource.to_string(),
+        String::from("colorbar source")
+    );
+}
+
+#[test]
+fn plot_key_comment_to_string() {
+    assert_eq!(
+        PlotKey::Comment(String::from("Fitted line")).to_string(),
+        String::from("% Fitted line")
+    );
+    assert_eq!(
+        PlotKey::Comment(String::from("Fitted line\nsee notes")).to_string(),
+        String::from("% Fitted line\n% see notes")
+    );
+}
+
+#[test]
+fn plot_key_name_path_to_string() {
+    assert_eq!(
+        PlotKey::NamePath(String::from("upper")).to_string(),
+        String::from("name path=upper")
+    );
+}
+
+#[test]
+fn plot_key_forget_plot_to_string() {
+    assert_eq!(PlotKey::ForgetPlot.to_string(), String::from("forget plot"));
+}
+
+#[test]
+fn plot_key_line_style_to_string() {
+    assert_eq!(
+        PlotKey::LineStyle(LineStyle::Dashed).to_string(),
+        String::from("dashed")
+    );
+}
+
+#[test]
+fn plot_key_line_width_to_string() {
+    assert_eq!(
+        PlotKey::LineWidth(LineWidth::VeryThick).to_string(),
+        String::from("very thick")
+    );
+    assert_eq!(
+        PlotKey::LineWidth(LineWidth::Pt(1.5)).to_string(),
+        String::from("line width=1.5pt")
+    );
+}
+
+#[test]
+fn plot_key_color_to_string() {
+    assert_eq!(
+        PlotKey::Color(Color::Rgb(12, 34, 56)).to_string(),
+        String::from("color={rgb,255:red,12;green,34;blue,56}")
+    );
+    assert_eq!(
+        PlotKey::Color(Color::Red).to_string(),
+        String::from("color={red}")
+    );
+}
+
+#[test]
+fn plot_key_mark_to_string() {
+    assert_eq!(PlotKey::Mark(Mark::Dot).to_string(), String::from("mark=*"));
+    assert_eq!(
+        PlotKey::Mark(Mark::None).to_string(),
+        String::from("mark=none")
+    );
+}
+
+#[test]
+fn plot_key_mark_size_to_string() {
+    assert_eq!(
+        PlotKey::MarkSize(2.0).to_string(),
+        String::from("mark size=2pt")
+    );
+    assert_eq!(
+        PlotKey::MarkSize(1.5).to_string(),
+        String::from("mark size=1.5pt")
+    );
+}
+
+#[test]
+fn plot_key_area_legend_to_string() {
+    assert_eq!(PlotKey::AreaLegend.to_string(), String::from("area legend"));
+}
+
+#[test]
+fn plot_key_fill_opacity_to_string() {
+    assert_eq!(
+        PlotKey::FillOpacity(0.5).to_string(),
+        String::from("fill opacity=0.5")
+    );
+}
+
+#[test]
+fn plot_key_draw_opacity_to_string() {
+    assert_eq!(
+        PlotKey::DrawOpacity(0.25).to_string(),
+        String::from("draw opacity=0.25")
+    );
+}
+
+#[test]
+fn plot_key_type_3d_to_string() {
+    assert_eq!(
+        PlotKey::Type3D(Type3D::Surf).to_string(),
+        String::from("surf")
+    );
+}
+
+#[test]
+fn plot_key_mesh_rows_to_string() {
+    assert_eq!(
+        PlotKey::MeshRows(20).to_string(),
+        String::from("mesh/rows=20")
+    );
+}
+
+#[test]
+fn plot_key_every_node_near_coord_style_to_string() {
+    assert_eq!(
+        PlotKey::EveryNodeNearCoordStyle(String::from("font=\\tiny")).to_string(),
+        String::from("every node near coord/.append style={font=\\tiny}")
+    );
+}
+
+#[test]
+fn plot_key_patch_to_string() {
+    assert_eq!(
+        PlotKey::Patch(PatchType::Triangle).to_string(),
+        String::from("patch, patch type=triangle")
+    );
+}
+
+#[test]
+fn plot_key_mark_indices_to_string() {
+    assert_eq!(
+        PlotKey::MarkIndices(vec![1, 5, 9]).to_string(),
+        String::from("mark indices={1,5,9}")
+    );
+    assert_eq!(
+        PlotKey::MarkIndices(Vec::new()).to_string(),
+        String::from("mark indices={}")
+    );
+}
+
+#[test]
+fn line_join_to_string() {
+    assert_eq!(LineJoin::Round.to_string(), String::from("round"));
+    assert_eq!(LineJoin::Miter.to_string(), String::from("miter"));
+    assert_eq!(LineJoin::Bevel.to_string(), String::from("bevel"));
+}
+
+#[test]
+fn line_cap_to_string() {
+    assert_eq!(LineCap::Round.to_string(), String::from("round"));
+    assert_eq!(LineCap::Rect.to_string(), String::from("rect"));
+    assert_eq!(LineCap::Butt.to_string(), String::from("butt"));
+}
+
+#[test]
+fn mark_to_string() {
+    assert_eq!(Mark::None.to_string(), String::from("none"));
+    assert_eq!(Mark::Dot.to_string(), String::from("*"));
+    assert_eq!(Mark::Circle.to_string(), String::from("o"));
+    assert_eq!(Mark::X.to_string(), String::from("x"));
+    assert_eq!(Mark::Plus.to_string(), String::from("+"));
+    assert_eq!(Mark::Star.to_string(), String::from("star"));
+    assert_eq!(Mark::Square.to_string(), String::from("square*"));
+    assert_eq!(Mark::Triangle.to_string(), String::from("triangle*"));
+    assert_eq!(Mark::Diamond.to_string(), String::from("diamond*"));
+    assert_eq!(
+        Mark::Custom(String::from("pentagon*")).to_string(),
+        String::from("pentagon*")
+    );
+}
+
+#[test]
+fn plot_2d_scatter_to_string() {
+    let plot = Plot2D::new()
+        .with_key(PlotKey::Type2D(Type2D::OnlyMarks))
+        .with_key(PlotKey::Mark(Mark::Square))
+        .with_key(PlotKey::MarkSize(2.0));
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[\n\t\tonly marks,\n\t\tmark=square*,\n\t\tmark size=2pt,\n\t] coordinates {\n\t};"
+    );
+}
+
+#[test]
+fn line_style_to_string() {
+    assert_eq!(LineStyle::Solid.to_string(), String::from("solid"));
+    assert_eq!(LineStyle::Dashed.to_string(), String::from("dashed"));
+    assert_eq!(LineStyle::Dotted.to_string(), String::from("dotted"));
+    assert_eq!(LineStyle::DashDot.to_string(), String::from("dash dot"));
+}
+
+#[test]
+fn line_width_to_string() {
+    assert_eq!(LineWidth::Thin.to_string(), String::from("thin"));
+    assert_eq!(LineWidth::Thick.to_string(), String::from("thick"));
+    assert_eq!(LineWidth::VeryThick.to_string(), String::from("very thick"));
+    assert_eq!(
+        LineWidth::Pt(2.0).to_string(),
+        String::from("line width=2pt")
+    );
+}
+
+#[test]
+fn plot_key_line_join_to_string() {
+    assert_eq!(
+        PlotKey::LineJoin(LineJoin::Round).to_string(),
+        String::from("line join=round")
+    );
+    assert_eq!(
+        PlotKey::LineJoin(LineJoin::Miter).to_string(),
+        String::from("line join=miter")
+    );
+    assert_eq!(
+        PlotKey::LineJoin(LineJoin::Bevel).to_string(),
+        String::from("line join=bevel")
+    );
+}
+
+#[test]
+fn plot_key_line_cap_to_string() {
+    assert_eq!(
+        PlotKey::LineCap(LineCap::Round).to_string(),
+        String::from("line cap=round")
+    );
+    assert_eq!(
+        PlotKey::LineCap(LineCap::Rect).to_string(),
+        String::from("line cap=rect")
+    );
+    assert_eq!(
+        PlotKey::LineCap(LineCap::Butt).to_string(),
+        String::from("line cap=butt")
+    );
+}
+
+#[test]
+fn unbounded_coords_to_string() {
+    assert_eq!(
+        UnboundedCoords::Discard.to_string(),
+        String::from("discard")
+    );
+    assert_eq!(UnboundedCoords::Jump.to_string(), String::from("jump"));
+}
+
+#[test]
+fn plot_key_unbounded_coords_to_string() {
+    assert_eq!(
+        PlotKey::UnboundedCoords(UnboundedCoords::Jump).to_string(),
+        String::from("unbounded coords=jump")
+    );
+    assert_eq!(
+        PlotKey::UnboundedCoords(UnboundedCoords::Discard).to_string(),
+        String::from("unbounded coords=discard")
+    );
+}
+
+#[test]
+fn plot_key_scatter_classes_to_string() {
+    assert_eq!(
+        PlotKey::ScatterClasses(vec![
+            String::from("mark=square*, blue"),
+            String::from("mark=triangle*, red")
+        ])
+        .to_string(),
+        String::from("scatter, scatter src=explicit symbolic, scatter/classes={0={mark=square*, blue}, 1={mark=triangle*, red}}")
+    );
+    assert_eq!(
+        PlotKey::ScatterClasses(Vec::new()).to_string(),
+        String::from("scatter, scatter src=explicit symbolic, scatter/classes={}")
+    );
+}
+
+#[test]
+fn nodes_near_coords_align_to_string() {
+    assert_eq!(
+        NodesNearCoordsAlign::Vertical.to_string(),
+        String::from("vertical")
+    );
+    assert_eq!(
+        NodesNearCoordsAlign::Horizontal.to_string(),
+        String::from("horizontal")
+    );
+}
+
+#[test]
+fn plot_key_nodes_near_coords_to_string() {
+    assert_eq!(
+        PlotKey::NodesNearCoords.to_string(),
+        String::from("nodes near coords")
+    );
+}
+
+#[test]
+fn plot_key_nodes_near_coords_align_to_string() {
+    assert_eq!(
+        PlotKey::NodesNearCoordsAlign(NodesNearCoordsAlign::Vertical).to_string(),
+        String::from("nodes near coords align={vertical}")
+    );
+}
+
+#[test]
+fn plot_key_nodes_near_coords_style_to_string() {
+    assert_eq!(
+        PlotKey::NodesNearCoordsStyle(String::from("font=\\tiny")).to_string(),
+        String::from("nodes near coords style={font=\\tiny}")
+    );
+}
+
 #[test]
 fn plot_key_custom_to_string() {
     assert_eq!(
@@ -251,6 +658,22 @@ fn plot_key_y_error_direction_to_string() {
     );
 }
 
+#[test]
+fn plot_key_y_error_plus_to_string() {
+    assert_eq!(
+        PlotKey::YErrorPlus(String::from("ep")).to_string(),
+        String::from("y error plus=\\thisrow{ep}")
+    );
+}
+
+#[test]
+fn plot_key_y_error_minus_to_string() {
+    assert_eq!(
+        PlotKey::YErrorMinus(String::from("em")).to_string(),
+        String::from("y error minus=\\thisrow{em}")
+    );
+}
+
 #[test]
 fn plot_2d_new() {
     let plot = Plot2D::new();
@@ -258,6 +681,29 @@ fn plot_2d_new() {
     assert!(plot.keys.is_empty());
 }
 
+#[test]
+fn plot_2d_push() {
+    let mut plot = Plot2D::new();
+    plot.push((1.0, 2.0));
+    assert_eq!(plot.coordinates.len(), 1);
+    assert_eq!(plot.coordinates[0].x, 1.0);
+    assert_eq!(plot.coordinates[0].y, 2.0);
+
+    plot.push((3.0, 4.0, None, Some(0.5)));
+    assert_eq!(plot.coordinates.len(), 2);
+    assert_eq!(plot.coordinates[1].error_y, Some(0.5));
+}
+
+#[test]
+fn plot_2d_prepend() {
+    let mut plot = Plot2D::new();
+    plot.prepend((1.0, 2.0));
+    plot.prepend((0.0, 1.0));
+    assert_eq!(plot.coordinates.len(), 2);
+    assert_eq!(plot.coordinates[0].x, 0.0);
+    assert_eq!(plot.coordinates[1].x, 1.0);
+}
+
 #[test]
 fn plot_2d_add_key() {
     let mut plot = Plot2D::new();
@@ -312,6 +758,23 @@ fn plot_2d_add_key() {
     );
 }
 
+#[test]
+fn plot_2d_with_key() {
+    let plot = Plot2D::new()
+        .with_key(PlotKey::Type2D(Type2D::SharpPlot))
+        .with_key(PlotKey::XError(ErrorCharacter::Absolute));
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(plot.keys[0].to_string(), String::from("sharp plot"));
+    assert_eq!(
+        plot.keys[1].to_string(),
+        String::from("error bars/x explicit")
+    );
+
+    let plot = plot.with_key(PlotKey::Type2D(Type2D::OnlyMarks));
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(plot.keys[1].to_string(), String::from("only marks"));
+}
+
 #[test]
 fn plot_2d_to_string() {
     let mut plot = Plot2D::new();
@@ -340,3 +803,505 @@ fn plot_2d_to_string() {
         "\t\\addplot[\n\t\tsharp plot,\n\t\terror bars/x explicit,\n\t\terror bars/x dir=both,\n\t] coordinates {\n\t\t(1,-1)\n\t\t(2,-2)\n\t\t(3,-3)\n\t};"
     );
 }
+
+#[test]
+fn plot_2d_label_bars() {
+    let mut plot = Plot2D::new();
+    plot.label_bars(2);
+    assert_eq!(plot.keys.len(), 3);
+    assert!(matches!(plot.keys[0], PlotKey::NodesNearCoords));
+    assert_eq!(
+        plot.keys[1].to_string(),
+        String::from("nodes near coords style={/pgf/number format/fixed, /pgf/number format/fixed zerofill, /pgf/number format/precision=2}")
+    );
+    assert_eq!(
+        plot.keys[2].to_string(),
+        String::from("nodes near coords align={vertical}")
+    );
+
+    plot.label_bars(0);
+    assert_eq!(plot.keys.len(), 3);
+    assert!(plot.keys[1].to_string().ends_with("precision=0}"));
+}
+
+#[test]
+fn plot_2d_as_translucent() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::YBar {
+        bar_width: 10.0,
+        bar_shift: 0.0,
+    }));
+    plot.as_translucent(0.8);
+    plot.as_translucent(0.5);
+    assert_eq!(plot.keys.len(), 3);
+    assert!(matches!(plot.keys[0], PlotKey::Type2D(Type2D::YBar { .. })));
+    assert_eq!(plot.keys[1].to_string(), String::from("fill opacity=0.5"));
+    assert_eq!(plot.keys[2].to_string(), String::from("draw opacity=0.5"));
+}
+
+#[test]
+fn plot_2d_bar_labels_rotated() {
+    let mut plot = Plot2D::new();
+    plot.bar_labels_rotated(45.0);
+    plot.bar_labels_rotated(90.0);
+    assert_eq!(plot.keys.len(), 2);
+    assert!(matches!(plot.keys[0], PlotKey::NodesNearCoords));
+    assert_eq!(
+        plot.keys[1].to_string(),
+        String::from("every node near coord/.append style={rotate=90, anchor=west}")
+    );
+}
+
+#[test]
+fn plot_2d_scatter_classes_to_string() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::ScatterClasses(vec![
+        String::from("blue"),
+        String::from("red"),
+    ]));
+    for class in 0..2 {
+        let mut coordinate: Coordinate2D = (class as f64, 1.0).into();
+        coordinate.class = Some(class);
+        plot.coordinates.push(coordinate);
+    }
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[\n\t\tscatter, scatter src=explicit symbolic, scatter/classes={0={blue}, 1={red}},\n\t] coordinates {\n\t\t(0,1)\t[0]\n\t\t(1,1)\t[1]\n\t};"
+    );
+
+    // A class without a style is defined with the default style.
+    plot.coordinates[1].class = Some(3);
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[\n\t\tscatter, scatter src=explicit symbolic, scatter/classes={0={blue}, 1={red}, 2={}, 3={}},\n\t] coordinates {\n\t\t(0,1)\t[0]\n\t\t(1,1)\t[3]\n\t};"
+    );
+}
+
+#[test]
+fn plot_2d_coordinate_style_to_string() {
+    let mut plot = Plot2D::new();
+    plot.push((0.0, 0.0));
+    plot.push((1.0, 1.0));
+    plot.push((2.0, 4.0));
+    plot.set_coordinate_style(1, "red");
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[] coordinates {\n\t\t(0,0)\n\t\t(1,1)\t[red]\n\t\t(2,4)\n\t};"
+    );
+
+    plot.prepend((-1.0, 1.0));
+    assert!(plot.to_string().contains("\t\t(0,0)\n\t\t(1,1)\t[red]\n"));
+
+    plot.coordinates[2].class = Some(0);
+    assert!(plot.to_string().contains("\t\t(1,1)\t[0]\n"));
+}
+
+#[test]
+fn plot_2d_coordinate_style_follows_coordinate() {
+    let mut plot: Plot2D = [(0.0, 0.0), (1.0, 1.0)].into_iter().collect();
+    plot.set_coordinate_style(0, "red");
+    plot.prepend((-1.0, 1.0));
+    plot.prepend((-2.0, 4.0));
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[] coordinates {\n\t\t(-2,4)\n\t\t(-1,1)\n\t\t(0,0)\t[red]\n\t\t(1,1)\n\t};"
+    );
+
+    plot.coordinates.reverse();
+    plot.coordinates.remove(0);
+    plot.set_coordinate_style(0, "blue");
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[] coordinates {\n\t\t(0,0)\t[blue]\n\t\t(-1,1)\n\t\t(-2,4)\n\t};"
+    );
+
+    let other: Plot2D = plot.coordinates.iter().copied().collect();
+    assert!(!other.to_string().contains("\t["));
+}
+
+#[test]
+#[should_panic(expected = "cannot style coordinate 1 of a plot with 1 coordinates")]
+fn plot_2d_set_coordinate_style_out_of_bounds() {
+    let mut plot = Plot2D::new();
+    plot.push((0.0, 0.0));
+    plot.set_coordinate_style(1, "red");
+}
+
+#[test]
+fn plot_2d_from_str() {
+    let plot: Plot2D = "1 2\n3 4\n5 6".parse().unwrap();
+    assert!(plot.keys.is_empty());
+    assert_eq!(plot.coordinates.len(), 3);
+    assert_eq!(plot.coordinates[2].x, 5.0);
+    assert_eq!(plot.coordinates[2].y, 6.0);
+
+    let plot: Plot2D = "# header\n\n  1 2 # comment\n\t\n3\t4\n".parse().unwrap();
+    assert_eq!(plot.coordinates.len(), 2);
+    assert_eq!(plot.coordinates[1].x, 3.0);
+
+    let plot: Plot2D = "".parse().unwrap();
+    assert!(plot.coordinates.is_empty());
+}
+
+#[test]
+fn plot_2d_from_str_invalid() {
+    let error = "1 2\n\n3 4 5\n".parse::<Plot2D>().unwrap_err();
+    assert_eq!(error.line, 3);
+    assert_eq!(error.source, ParseCoordinateError::InvalidLength(3));
+
+    let error = "1 x".parse::<Plot2D>().unwrap_err();
+    assert_eq!(error.line, 1);
+    assert!(matches!(
+        error.source,
+        ParseCoordinateError::InvalidNumber(_)
+    ));
+}
+
+#[test]
+fn plot_2d_resample_linear() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    plot.coordinates = vec![(0.0, 0.0).into(), (1.0, 10.0).into(), (3.0, 0.0).into()];
+
+    let resampled = plot.resample_linear(&[-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(resampled.keys.len(), 1);
+    let xs: Vec<f64> = resampled.coordinates.iter().map(|c| c.x).collect();
+    let ys: Vec<f64> = resampled.coordinates.iter().map(|c| c.y).collect();
+    assert_eq!(xs, vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(ys, vec![0.0, 0.0, 5.0, 10.0, 5.0, 0.0, 0.0]);
+
+    assert!(Plot2D::new().resample_linear(&[1.0]).coordinates.is_empty());
+    assert!(plot.resample_linear(&[]).coordinates.is_empty());
+}
+
+#[test]
+fn plot_2d_clamp_y() {
+    let mut plot = Plot2D::new();
+    for y in [-5.0, 0.5, 5.0, f64::NAN] {
+        plot.push((1.0, y));
+    }
+    plot.clamp_y(-1.0, 1.0);
+    assert_eq!(plot.coordinates[0].y, -1.0);
+    assert_eq!(plot.coordinates[1].y, 0.5);
+    assert_eq!(plot.coordinates[2].y, 1.0);
+    assert!(plot.coordinates[3].y.is_nan());
+    assert!(plot.coordinates.iter().all(|c| c.x == 1.0));
+}
+
+#[test]
+fn plot_2d_cumulative_y() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::ConstLeft));
+    plot.push((0.0, 1.0, None, Some(0.5)));
+    plot.push((1.0, 2.0));
+    plot.push((2.0, 3.0));
+
+    let cumulative = plot.cumulative_y();
+    assert_eq!(cumulative.keys.len(), 1);
+    let xs: Vec<f64> = cumulative.coordinates.iter().map(|c| c.x).collect();
+    let ys: Vec<f64> = cumulative.coordinates.iter().map(|c| c.y).collect();
+    assert_eq!(xs, vec![0.0, 1.0, 2.0]);
+    assert_eq!(ys, vec![1.0, 3.0, 6.0]);
+    assert!(cumulative.coordinates[0].error_y.is_none());
+
+    assert!(Plot2D::new().cumulative_y().coordinates.is_empty());
+}
+
+#[test]
+fn plot_2d_derivative() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    for x in [0.0, 0.5, 2.0, 3.0] {
+        plot.push((x, 3.0 * x - 1.0));
+    }
+
+    let derivative = plot.derivative();
+    assert_eq!(derivative.keys.len(), 1);
+    let xs: Vec<f64> = derivative.coordinates.iter().map(|c| c.x).collect();
+    assert_eq!(xs, vec![0.0, 0.5, 2.0, 3.0]);
+    for coordinate in derivative.coordinates.iter() {
+        assert!((coordinate.y - 3.0).abs() < 1e-12);
+    }
+
+    let mut single = Plot2D::new();
+    single.push((1.0, 1.0));
+    assert!(single.derivative().coordinates.is_empty());
+    assert!(Plot2D::new().derivative().coordinates.is_empty());
+}
+
+#[test]
+fn plot_2d_derivative_duplicated_x() {
+    let mut plot = Plot2D::new();
+    for (x, y) in [(0.0, 0.0), (1.0, 1.0), (1.0, 3.0), (2.0, 4.0)] {
+        plot.push((x, y));
+    }
+
+    let derivative = plot.derivative();
+    let values: Vec<(f64, f64)> = derivative.coordinates.iter().map(|c| (c.x, c.y)).collect();
+    assert_eq!(values, vec![(0.0, 2.0), (1.0, 2.0), (2.0, 2.0)]);
+    assert!(derivative.coordinates.iter().all(|c| c.y.is_finite()));
+
+    let mut vertical = Plot2D::new();
+    vertical.push((1.0, 0.0));
+    vertical.push((1.0, 5.0));
+    assert!(vertical.derivative().coordinates.is_empty());
+}
+
+#[test]
+fn plot_2d_shift() {
+    let mut plot = Plot2D::new();
+    plot.push((1.0, 2.0, Some(0.1), Some(0.2)));
+    plot.push((-1.0, 0.0));
+    plot.shift(0.5, -2.0);
+    assert_eq!(plot.coordinates[0].x, 1.5);
+    assert_eq!(plot.coordinates[0].y, 0.0);
+    assert_eq!(plot.coordinates[0].error_x, Some(0.1));
+    assert_eq!(plot.coordinates[0].error_y, Some(0.2));
+    assert_eq!(plot.coordinates[1].x, -0.5);
+    assert_eq!(plot.coordinates[1].y, -2.0);
+}
+
+#[test]
+fn plot_2d_scale() {
+    let mut plot = Plot2D::new();
+    plot.push((1.0, 2.0, Some(0.5), Some(0.25)));
+    plot.push((-1.0, 0.0));
+    plot.scale(-2.0, 4.0);
+    assert_eq!(plot.coordinates[0].x, -2.0);
+    assert_eq!(plot.coordinates[0].y, 8.0);
+    assert_eq!(plot.coordinates[0].error_x, Some(1.0));
+    assert_eq!(plot.coordinates[0].error_y, Some(1.0));
+    assert_eq!(plot.coordinates[1].x, 2.0);
+    assert_eq!(plot.coordinates[1].y, 0.0);
+    assert!(plot.coordinates[1].error_x.is_none());
+    assert!(plot.coordinates[1].error_y.is_none());
+}
+
+#[test]
+fn plot_2d_patch_triangles() {
+    let plot = Plot2D::patch_triangles(vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]).unwrap();
+    assert_eq!(plot.libraries(), vec![Library::PatchPlots]);
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[\n\t\tpatch, patch type=triangle,\n\t] coordinates {\n\t\t(0,0)\n\t\t(1,0)\n\t\t(0,1)\n\t};"
+    );
+
+    assert!(Plot2D::patch_triangles(Vec::<(f64, f64)>::new()).is_ok());
+    assert_eq!(
+        Plot2D::patch_triangles(vec![(0.0, 0.0); 4]).unwrap_err(),
+        PatchError {
+            vertices: 4,
+            per_patch: 3
+        }
+    );
+    assert!(Plot2D::new().libraries().is_empty());
+}
+
+#[test]
+fn plot_2d_set_legend_entry() {
+    let mut plot = Plot2D::new();
+    assert_eq!(plot.legend_entry(), None);
+    plot.set_legend_entry("$y = x^2$");
+    assert_eq!(plot.legend_entry(), Some("$y = x^2$"));
+    // The entry is written by the axis, not by the plot.
+    assert_eq!(plot.to_string(), "\t\\addplot[] coordinates {\n\t};");
+}
+
+#[test]
+fn plot_2d_from_fn() {
+    assert!(Plot2D::from_fn(0.0..=1.0, 0, |x| x).coordinates.is_empty());
+
+    let plot = Plot2D::from_fn(-1.0..=1.0, 1, |x| 2.0 * x);
+    assert_eq!(plot.coordinates.len(), 1);
+    assert_eq!((plot.coordinates[0].x, plot.coordinates[0].y), (-1.0, -2.0));
+
+    let plot = Plot2D::from_fn(0.0..=0.3, 4, |x| x);
+    let x: Vec<f64> = plot.coordinates.iter().map(|c| c.x).collect();
+    assert_eq!(x.len(), 4);
+    assert_eq!(x[0], 0.0);
+    assert_eq!(x[3], 0.3);
+    assert!((x[1] - 0.1).abs() < 1e-12 && (x[2] - 0.2).abs() < 1e-12);
+}
+
+#[test]
+fn plot_2d_comment_to_string() {
+    let mut plot = Plot2D::new()
+        .with_key(PlotKey::Comment(String::from("Measured data\nrun 3")))
+        .with_key(PlotKey::Type2D(Type2D::OnlyMarks));
+    plot.push((1.0, 2.0));
+    assert_eq!(
+        plot.to_string(),
+        "\t% Measured data\n\t% run 3\n\t\\addplot[\n\t\tonly marks,\n\t] coordinates {\n\t\t(1,2)\n\t};"
+    );
+
+    let plot = Plot3D::new().with_key(PlotKey::Comment(String::from("Surface")));
+    assert_eq!(
+        plot.to_string(),
+        "\t% Surface\n\t\\addplot3[] coordinates {\n\t};"
+    );
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn plot_2d_from_csv() {
+    let data = "x,y,z\r\n1, 2 ,3\r\n\r\n4,5,6\r\n";
+    let plot = Plot2D::from_csv(data.as_bytes(), 2, 0, true).unwrap();
+    let coordinates: Vec<(f64, f64)> = plot.coordinates.iter().map(|c| (c.x, c.y)).collect();
+    assert_eq!(coordinates, vec![(3.0, 1.0), (6.0, 4.0)]);
+
+    let error = Plot2D::from_csv(data.as_bytes(), 0, 1, false).unwrap_err();
+    assert!(matches!(
+        error,
+        CsvError::InvalidNumber {
+            row: 1,
+            column: 0,
+            ..
+        }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "invalid number in row 1, column 0: invalid float literal"
+    );
+
+    let error = Plot2D::from_csv(data.as_bytes(), 0, 3, true).unwrap_err();
+    assert!(matches!(
+        error,
+        CsvError::MissingColumn { row: 2, column: 3 }
+    ));
+    assert_eq!(error.to_string(), "row 2 has no column 3");
+}
+
+#[test]
+fn bin_counts_values() {
+    assert_eq!(
+        bin_counts(&[3.0, 0.0, 2.0, 1.0], 2),
+        vec![(0.75, 2.0), (2.25, 2.0)]
+    );
+    assert_eq!(
+        bin_counts(&[0.0, 0.5, 4.0, f64::NAN], 4),
+        vec![(0.5, 2.0), (1.5, 0.0), (2.5, 0.0), (3.5, 1.0)]
+    );
+    assert_eq!(bin_counts(&[2.0, 2.0, 2.0], 5), vec![(2.0, 3.0)]);
+    assert!(bin_counts(&[1.0, 2.0], 0).is_empty());
+    assert!(bin_counts(&[f64::INFINITY], 3).is_empty());
+}
+
+#[test]
+fn plot_2d_from_iter_extend() {
+    let mut plot: Plot2D = vec![(0.0, 0.0), (1.0, 1.0)].into_iter().collect();
+    let mut expected = Plot2D::new();
+    expected.push((0.0, 0.0));
+    expected.push((1.0, 1.0));
+    assert_eq!(plot.to_string(), expected.to_string());
+    plot.extend(vec![Coordinate2D::with_y_error(2.0, 4.0, 0.5)]);
+    assert_eq!(plot.coordinates.len(), 3);
+    assert_eq!(plot.coordinates[2].error_y, Some(0.5));
+
+    let plot: Plot2D = plot.coordinates.into_iter().collect();
+    assert_eq!(plot.coordinates.len(), 3);
+    assert_eq!(plot.legend_entry(), None);
+}
+
+#[test]
+fn plot_2d_set_precision() {
+    let mut plot = Plot2D::new();
+    plot.push((1.2300000001, 2.0 / 3.0, Some(0.0151), None));
+    plot.push((-0.004, 12.5));
+    plot.set_precision(2);
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[\n\t\terror bars/x explicit,\n\t\terror bars/x dir=both,\n\t] coordinates {\n\t\t(1.23,0.67)\t+- (0.02,0)\n\t\t(0,12.5)\n\t};"
+    );
+    assert_eq!(plot.coordinates[0].x, 1.2300000001);
+
+    plot.set_precision(0);
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[\n\t\terror bars/x explicit,\n\t\terror bars/x dir=both,\n\t] coordinates {\n\t\t(1,1)\t+- (0,0)\n\t\t(0,12)\n\t};"
+    );
+}
+
+#[test]
+fn plot_3d_new() {
+    let plot = Plot3D::new();
+    assert!(plot.keys.is_empty());
+    assert!(plot.coordinates.is_empty());
+}
+
+#[test]
+fn plot_3d_add_key() {
+    let mut plot = Plot3D::new();
+    plot.add_key(PlotKey::Type3D(Type3D::Mesh));
+    plot.add_key(PlotKey::Type3D(Type3D::Surf));
+    plot.add_key(PlotKey::Custom(String::from("shader=interp")));
+    plot.add_key(PlotKey::Custom(String::from("opacity=0.5")));
+    assert_eq!(plot.keys.len(), 3);
+    assert!(matches!(plot.keys[0], PlotKey::Type3D(Type3D::Surf)));
+}
+
+#[test]
+fn plot_3d_to_string() {
+    let mut plot = Plot3D::new();
+    assert_eq!(plot.to_string(), "\t\\addplot3[] coordinates {\n\t};");
+
+    plot.push((0.0, 0.0, 0.0));
+    plot.push((0.0, 1.0, f64::NAN));
+    plot.add_key(PlotKey::Type3D(Type3D::Surf));
+    plot.add_key(PlotKey::MeshRows(1));
+    plot.add_key(PlotKey::UnboundedCoords(UnboundedCoords::Jump));
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot3[\n\t\tsurf,\n\t\tmesh/rows=1,\n\t\tunbounded coords=jump,\n\t] coordinates {\n\t\t(0,0,0)\n\t\t(0,1,NaN)\n\t};"
+    );
+}
+
+#[test]
+fn plot_3d_legend_entry_area_legend() {
+    let mut plot = Plot3D::new().with_key(PlotKey::Type3D(Type3D::Mesh));
+    assert!(!plot.to_string().contains("area legend"));
+
+    plot.set_legend_entry("mesh");
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot3[\n\t\tmesh,\n\t\tarea legend,\n\t] coordinates {\n\t};"
+    );
+
+    plot.add_key(PlotKey::AreaLegend);
+    assert_eq!(plot.to_string().matches("area legend").count(), 1);
+
+    let scatter = Plot3D::new()
+        .with_key(PlotKey::Type3D(Type3D::Scatter))
+        .with_legend_entry("points");
+    assert!(!scatter.to_string().contains("area legend"));
+}
+
+#[test]
+fn plot_2d_implied_error_keys() {
+    let mut plot = Plot2D::new();
+    plot.push((0.0, 1.0));
+    assert!(plot.implied_error_keys().is_empty());
+
+    plot.push(Coordinate2D::with_y_error(1.0, 2.0, 0.5));
+    plot.push((2.0, 3.0));
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[\n\t\terror bars/y explicit,\n\t\terror bars/y dir=both,\n\t] coordinates {\n\t\t(0,1)\n\t\t(1,2)\t+- (0,0.5)\n\t\t(2,3)\n\t};"
+    );
+
+    // Keys that are set explicitly are not overwritten.
+    plot.add_key(PlotKey::YErrorDirection(ErrorDirection::Plus));
+    plot.push(Coordinate2D::with_x_error(3.0, 4.0, 0.1));
+    let keys: Vec<String> = plot
+        .implied_error_keys()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            "error bars/x explicit",
+            "error bars/x dir=both",
+            "error bars/y explicit"
+        ]
+    );
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::axis::plot::{PlotKey, *};
+use crate::units::Length;
 
 #[test]
 fn scale_to_string() {
@@ -24,9 +25,654 @@ fn axis_keys_tested() {
         AxisKey::Title(_) => (),
         AxisKey::XLabel(_) => (),
         AxisKey::YLabel(_) => (),
+        AxisKey::MaxSpaceBetweenTicks(_) => (),
+        AxisKey::TryMinTicks(_) => (),
+        AxisKey::ClipLimits(_) => (),
+        AxisKey::EnlargeLimits(_) => (),
+        AxisKey::EnlargeXLimits(_) => (),
+        AxisKey::EnlargeYLimits(_) => (),
+        AxisKey::Samples(_) => (),
+        AxisKey::AxisXDiscontinuity(_) => (),
+        AxisKey::AxisYDiscontinuity(_) => (),
+        AxisKey::PointMeta(_) => (),
+        AxisKey::Grid(_) => (),
+        AxisKey::MajorGridStyle(_) => (),
+        AxisKey::MinorGridStyle(_) => (),
+        AxisKey::TickLabelStyle(_) => (),
+        AxisKey::XTickLabelStyle(_) => (),
+        AxisKey::YTickLabelStyle(_) => (),
+        AxisKey::FilterDiscardWarning(_) => (),
+        AxisKey::XLabelShift(_) => (),
+        AxisKey::YLabelShift(_) => (),
+        AxisKey::ColorbarHorizontal(_) => (),
+        AxisKey::ColorbarSampleLine(_) => (),
+        AxisKey::EveryMarkStyle(_) => (),
+        AxisKey::TitleStyle(_) => (),
+        AxisKey::Width(_) => (),
+        AxisKey::Height(_) => (),
+        AxisKey::ScaleOnlyAxis(_) => (),
+        AxisKey::EveryMarkScale(_) => (),
+        AxisKey::XTick(_) => (),
+        AxisKey::XCoordTrafo(_) => (),
+        AxisKey::XCoordInvTrafo(_) => (),
+        AxisKey::LegendCellAlign(_) => (),
+        AxisKey::ZLabel(_) => (),
+        AxisKey::View {
+            azimuth: _,
+            elevation: _,
+        } => (),
+        AxisKey::XTickPos(_) => (),
+        AxisKey::YTickPos(_) => (),
+        AxisKey::LogBasisX(_) => (),
+        AxisKey::LogBasisY(_) => (),
+        AxisKey::LegendPos(_) => (),
+        AxisKey::EveryYTickScaleLabelStyle(_) => (),
+        AxisKey::XMin(_) => (),
+        AxisKey::XMax(_) => (),
+        AxisKey::YMin(_) => (),
+        AxisKey::YMax(_) => (),
+        AxisKey::YBar => (),
+        AxisKey::ReverseLegend(_) => (),
+        AxisKey::YLabelStyle(_) => (),
+        AxisKey::AxisEqual(_) => (),
+        AxisKey::AxisEqualImage(_) => (),
+        AxisKey::SeparateAxisLines(_) => (),
+        AxisKey::XAxisLineStyle(_) => (),
+        AxisKey::YAxisLineStyle(_) => (),
+        AxisKey::ColorbarSource(_) => (),
+        AxisKey::Colormap(_) => (),
+        AxisKey::Colorbar(_) => (),
+        AxisKey::XTickDistance(_) => (),
+        AxisKey::YTickDistance(_) => (),
+        AxisKey::BarStacking(_) => (),
     }
 }
 
+#[test]
+fn axis_key_bar_stacking_to_string() {
+    assert_eq!(
+        AxisKey::BarStacking(Stacking::Stacked).to_string(),
+        String::from("ybar stacked")
+    );
+}
+
+#[test]
+fn axis_key_x_tick_distance_to_string() {
+    assert_eq!(
+        AxisKey::XTickDistance(2.0).to_string(),
+        String::from("xtick distance=2")
+    );
+}
+
+#[test]
+fn axis_key_y_tick_distance_to_string() {
+    assert_eq!(
+        AxisKey::YTickDistance(0.5).to_string(),
+        String::from("ytick distance=0.5")
+    );
+}
+
+#[test]
+fn axis_key_colormap_to_string() {
+    assert_eq!(
+        AxisKey::Colormap(Colormap::Viridis).to_string(),
+        String::from("colormap/viridis")
+    );
+}
+
+#[test]
+fn axis_key_colorbar_to_string() {
+    assert_eq!(
+        AxisKey::Colorbar(true).to_string(),
+        String::from("colorbar=true")
+    );
+    assert_eq!(
+        AxisKey::Colorbar(false).to_string(),
+        String::from("colorbar=false")
+    );
+}
+
+#[test]
+fn axis_key_colorbar_source_to_string() {
+    assert_eq!(
+        AxisKey::ColorbarSource(true).to_string(),
+        String::from("colorbar source=true")
+    );
+    assert_eq!(
+        AxisKey::ColorbarSource(false).to_string(),
+        String::from("colorbar source=false")
+    );
+}
+
+#[test]
+fn axis_key_separate_axis_lines_to_string() {
+    assert_eq!(
+        AxisKey::SeparateAxisLines(true).to_string(),
+        String::from("separate axis lines=true")
+    );
+}
+
+#[test]
+fn axis_key_x_axis_line_style_to_string() {
+    assert_eq!(
+        AxisKey::XAxisLineStyle(String::from("yshift=-5pt")).to_string(),
+        String::from("x axis line style={yshift=-5pt}")
+    );
+}
+
+#[test]
+fn axis_key_y_axis_line_style_to_string() {
+    assert_eq!(
+        AxisKey::YAxisLineStyle(String::from("xshift=-5pt")).to_string(),
+        String::from("y axis line style={xshift=-5pt}")
+    );
+}
+
+#[test]
+fn axis_key_axis_equal_to_string() {
+    assert_eq!(
+        AxisKey::AxisEqual(true).to_string(),
+        String::from("axis equal=true")
+    );
+}
+
+#[test]
+fn axis_key_axis_equal_image_to_string() {
+    assert_eq!(
+        AxisKey::AxisEqualImage(false).to_string(),
+        String::from("axis equal image=false")
+    );
+}
+
+#[test]
+fn axis_key_y_label_style_to_string() {
+    assert_eq!(
+        AxisKey::YLabelStyle(String::from("red")).to_string(),
+        String::from("ylabel style={red}")
+    );
+}
+
+#[test]
+fn axis_key_reverse_legend_to_string() {
+    assert_eq!(
+        AxisKey::ReverseLegend(true).to_string(),
+        String::from("reverse legend=true")
+    );
+    assert_eq!(
+        AxisKey::ReverseLegend(false).to_string(),
+        String::from("reverse legend=false")
+    );
+}
+
+#[test]
+fn axis_key_ybar_to_string() {
+    assert_eq!(AxisKey::YBar.to_string(), String::from("ybar"));
+}
+
+#[test]
+fn axis_key_xmin_to_string() {
+    assert_eq!(AxisKey::XMin(-1.0).to_string(), String::from("xmin=-1"));
+}
+
+#[test]
+fn axis_key_xmax_to_string() {
+    assert_eq!(AxisKey::XMax(2.5).to_string(), String::from("xmax=2.5"));
+}
+
+#[test]
+fn axis_key_ymin_to_string() {
+    assert_eq!(AxisKey::YMin(-1.0).to_string(), String::from("ymin=-1"));
+}
+
+#[test]
+fn axis_key_ymax_to_string() {
+    assert_eq!(AxisKey::YMax(2.5).to_string(), String::from("ymax=2.5"));
+}
+
+#[test]
+fn axis_key_every_y_tick_scale_label_style_to_string() {
+    assert_eq!(
+        AxisKey::EveryYTickScaleLabelStyle(String::from(
+            "at={(yticklabel cs:1)}, anchor=south west"
+        ))
+        .to_string(),
+        String::from("every y tick scale label/.style={at={(yticklabel cs:1)}, anchor=south west}")
+    );
+}
+
+#[test]
+fn axis_key_legend_pos_to_string() {
+    assert_eq!(
+        AxisKey::LegendPos(LegendPos::NorthWest).to_string(),
+        String::from("legend pos=north west")
+    );
+}
+
+#[test]
+fn axis_key_log_basis_x_to_string() {
+    assert_eq!(
+        AxisKey::LogBasisX(2.0).to_string(),
+        String::from("log basis x=2")
+    );
+}
+
+#[test]
+fn axis_key_log_basis_y_to_string() {
+    assert_eq!(
+        AxisKey::LogBasisY(2.5).to_string(),
+        String::from("log basis y=2.5")
+    );
+}
+
+#[test]
+fn axis_key_x_tick_pos_to_string() {
+    assert_eq!(
+        AxisKey::XTickPos(TickPos::Left).to_string(),
+        String::from("xtick pos=left")
+    );
+}
+
+#[test]
+fn axis_key_y_tick_pos_to_string() {
+    assert_eq!(
+        AxisKey::YTickPos(TickPos::Both).to_string(),
+        String::from("ytick pos=both")
+    );
+}
+
+#[test]
+fn axis_key_z_label_to_string() {
+    assert_eq!(
+        AxisKey::ZLabel(String::from("$z$")).to_string(),
+        String::from("zlabel={$z$}")
+    );
+}
+
+#[test]
+fn axis_key_view_to_string() {
+    assert_eq!(
+        AxisKey::View {
+            azimuth: 25.0,
+            elevation: 30.5
+        }
+        .to_string(),
+        String::from("view={25}{30.5}")
+    );
+}
+
+#[test]
+fn axis_key_legend_cell_align_to_string() {
+    assert_eq!(
+        AxisKey::LegendCellAlign(Align::Left).to_string(),
+        String::from("legend cell align=left")
+    );
+}
+
+#[test]
+fn axis_key_x_coord_trafo_to_string() {
+    assert_eq!(
+        AxisKey::XCoordTrafo(String::from("\\pgfmathparse{#1^2}")).to_string(),
+        String::from("x coord trafo/.code={\\pgfmathparse{#1^2}}")
+    );
+}
+
+#[test]
+fn axis_key_x_coord_inv_trafo_to_string() {
+    assert_eq!(
+        AxisKey::XCoordInvTrafo(String::from("\\pgfmathparse{sqrt(#1)}")).to_string(),
+        String::from("x coord inv trafo/.code={\\pgfmathparse{sqrt(#1)}}")
+    );
+}
+
+#[test]
+fn axis_key_x_tick_to_string() {
+    assert_eq!(
+        AxisKey::XTick(vec![0.0, 1.5, 3.0]).to_string(),
+        String::from("xtick={0,1.5,3}")
+    );
+}
+
+#[test]
+fn axis_key_every_mark_scale_to_string() {
+    assert_eq!(
+        AxisKey::EveryMarkScale(1.5).to_string(),
+        String::from("every mark/.append style={mark size=1.5pt}")
+    );
+}
+
+#[test]
+fn axis_key_width_to_string() {
+    assert_eq!(
+        AxisKey::Width(Length::Cm(8.0)).to_string(),
+        String::from("width=8cm")
+    );
+}
+
+#[test]
+fn axis_key_height_to_string() {
+    assert_eq!(
+        AxisKey::Height(Length::Pt(150.0)).to_string(),
+        String::from("height=150pt")
+    );
+}
+
+#[test]
+fn axis_key_scale_only_axis_to_string() {
+    assert_eq!(
+        AxisKey::ScaleOnlyAxis(true).to_string(),
+        String::from("scale only axis=true")
+    );
+    assert_eq!(
+        AxisKey::ScaleOnlyAxis(false).to_string(),
+        String::from("scale only axis=false")
+    );
+}
+
+#[test]
+fn axis_key_title_style_to_string() {
+    assert_eq!(
+        AxisKey::TitleStyle(String::from("yshift=5pt")).to_string(),
+        String::from("title style={yshift=5pt}")
+    );
+}
+
+#[test]
+fn axis_key_every_mark_style_to_string() {
+    assert_eq!(
+        AxisKey::EveryMarkStyle(String::from("solid, fill=white")).to_string(),
+        String::from("every mark/.append style={solid, fill=white}")
+    );
+}
+
+#[test]
+fn axis_key_colorbar_horizontal_to_string() {
+    assert_eq!(
+        AxisKey::ColorbarHorizontal(true).to_string(),
+        String::from("colorbar horizontal")
+    );
+    assert_eq!(
+        AxisKey::ColorbarHorizontal(false).to_string(),
+        String::from("colorbar right")
+    );
+}
+
+#[test]
+fn axis_key_colorbar_sample_line_to_string() {
+    assert_eq!(
+        AxisKey::ColorbarSampleLine(String::from("scatter, only marks")).to_string(),
+        String::from("colorbar sampled line={scatter, only marks}")
+    );
+}
+
+#[test]
+fn axis_key_x_label_shift_to_string() {
+    assert_eq!(
+        AxisKey::XLabelShift(Length::Pt(-5.0)).to_string(),
+        String::from("xlabel shift=-5pt")
+    );
+}
+
+#[test]
+fn axis_key_y_label_shift_to_string() {
+    assert_eq!(
+        AxisKey::YLabelShift(Length::Pt(-5.0)).to_string(),
+        String::from("ylabel shift=-5pt")
+    );
+    assert_eq!(
+        AxisKey::YLabelShift(Length::Mm(2.0)).to_string(),
+        String::from("ylabel shift=2mm")
+    );
+}
+
+#[test]
+fn axis_key_filter_discard_warning_to_string() {
+    assert_eq!(
+        AxisKey::FilterDiscardWarning(false).to_string(),
+        String::from("filter discard warning=false")
+    );
+    assert_eq!(
+        AxisKey::FilterDiscardWarning(true).to_string(),
+        String::from("filter discard warning=true")
+    );
+}
+
+#[test]
+fn axis_key_tick_label_style_to_string() {
+    assert_eq!(
+        AxisKey::TickLabelStyle(String::from("font=\\tiny")).to_string(),
+        String::from("tick label style={font=\\tiny}")
+    );
+}
+
+#[test]
+fn axis_key_x_tick_label_style_to_string() {
+    assert_eq!(
+        AxisKey::XTickLabelStyle(String::from("rotate=90")).to_string(),
+        String::from("x tick label style={rotate=90}")
+    );
+}
+
+#[test]
+fn axis_key_y_tick_label_style_to_string() {
+    assert_eq!(
+        AxisKey::YTickLabelStyle(String::from("rotate=90")).to_string(),
+        String::from("y tick label style={rotate=90}")
+    );
+}
+
+#[test]
+fn align_to_string() {
+    assert_eq!(Align::Left.to_string(), String::from("left"));
+    assert_eq!(Align::Right.to_string(), String::from("right"));
+    assert_eq!(Align::Center.to_string(), String::from("center"));
+}
+
+#[test]
+fn tick_pos_to_string() {
+    assert_eq!(TickPos::Left.to_string(), String::from("left"));
+    assert_eq!(TickPos::Right.to_string(), String::from("right"));
+    assert_eq!(TickPos::Both.to_string(), String::from("both"));
+}
+
+#[test]
+fn legend_pos_to_string() {
+    assert_eq!(LegendPos::SouthWest.to_string(), String::from("south west"));
+    assert_eq!(LegendPos::SouthEast.to_string(), String::from("south east"));
+    assert_eq!(LegendPos::NorthWest.to_string(), String::from("north west"));
+    assert_eq!(LegendPos::NorthEast.to_string(), String::from("north east"));
+    assert_eq!(
+        LegendPos::OuterNorthEast.to_string(),
+        String::from("outer north east")
+    );
+}
+
+#[test]
+fn colormap_to_string() {
+    assert_eq!(Colormap::Viridis.to_string(), String::from("viridis"));
+    assert_eq!(Colormap::Hot.to_string(), String::from("hot"));
+    assert_eq!(Colormap::Jet.to_string(), String::from("jet"));
+    assert_eq!(Colormap::Cool.to_string(), String::from("cool"));
+    assert_eq!(Colormap::BlueRed.to_string(), String::from("bluered"));
+    assert_eq!(Colormap::BlackWhite.to_string(), String::from("blackwhite"));
+}
+
+#[test]
+fn stacking_to_string() {
+    assert_eq!(Stacking::Stacked.to_string(), String::from("ybar stacked"));
+    assert_eq!(
+        Stacking::StackedReversed.to_string(),
+        String::from("ybar stacked, reverse legend")
+    );
+}
+
+#[test]
+fn grid_style_to_string() {
+    assert_eq!(GridStyle::None.to_string(), String::from("none"));
+    assert_eq!(GridStyle::Major.to_string(), String::from("major"));
+    assert_eq!(GridStyle::Minor.to_string(), String::from("minor"));
+    assert_eq!(GridStyle::Both.to_string(), String::from("both"));
+}
+
+#[test]
+fn axis_key_grid_to_string() {
+    assert_eq!(
+        AxisKey::Grid(GridStyle::Both).to_string(),
+        String::from("grid=both")
+    );
+    assert_eq!(
+        AxisKey::Grid(GridStyle::Major).to_string(),
+        String::from("grid=major")
+    );
+}
+
+#[test]
+fn axis_key_major_grid_style_to_string() {
+    assert_eq!(
+        AxisKey::MajorGridStyle(String::from("dashed, gray!40")).to_string(),
+        String::from("major grid style={dashed, gray!40}")
+    );
+}
+
+#[test]
+fn axis_key_minor_grid_style_to_string() {
+    assert_eq!(
+        AxisKey::MinorGridStyle(String::from("dotted, gray!20")).to_string(),
+        String::from("minor grid style={dotted, gray!20}")
+    );
+}
+
+#[test]
+fn point_meta_to_string() {
+    assert_eq!(PointMeta::Explicit.to_string(), String::from("explicit"));
+    assert_eq!(PointMeta::X.to_string(), String::from("x"));
+    assert_eq!(PointMeta::Y.to_string(), String::from("y"));
+    assert_eq!(
+        PointMeta::F(String::from("x*y")).to_string(),
+        String::from("{x*y}")
+    );
+}
+
+#[test]
+fn axis_key_point_meta_to_string() {
+    assert_eq!(
+        AxisKey::PointMeta(PointMeta::Y).to_string(),
+        String::from("point meta=y")
+    );
+    assert_eq!(
+        AxisKey::PointMeta(PointMeta::F(String::from("\\thisrow{z}"))).to_string(),
+        String::from("point meta={\\thisrow{z}}")
+    );
+}
+
+#[test]
+fn axis_discontinuity_to_string() {
+    assert_eq!(AxisDiscontinuity::None.to_string(), String::from("none"));
+    assert_eq!(
+        AxisDiscontinuity::Crunch.to_string(),
+        String::from("crunch")
+    );
+    assert_eq!(
+        AxisDiscontinuity::Parallel.to_string(),
+        String::from("parallel")
+    );
+}
+
+#[test]
+fn axis_key_axis_x_discontinuity_to_string() {
+    assert_eq!(
+        AxisKey::AxisXDiscontinuity(AxisDiscontinuity::Crunch).to_string(),
+        String::from("axis x discontinuity=crunch")
+    );
+}
+
+#[test]
+fn axis_key_axis_y_discontinuity_to_string() {
+    assert_eq!(
+        AxisKey::AxisYDiscontinuity(AxisDiscontinuity::Parallel).to_string(),
+        String::from("axis y discontinuity=parallel")
+    );
+}
+
+#[test]
+fn axis_key_samples_to_string() {
+    assert_eq!(
+        AxisKey::Samples(100).to_string(),
+        String::from("samples=100")
+    );
+}
+
+#[test]
+fn enlarge_limits_to_string() {
+    assert_eq!(EnlargeLimits::True.to_string(), String::from("true"));
+    assert_eq!(EnlargeLimits::False.to_string(), String::from("false"));
+    assert_eq!(EnlargeLimits::Rel(0.15).to_string(), String::from("0.15"));
+    assert_eq!(EnlargeLimits::Abs(5.0).to_string(), String::from("{abs=5}"));
+}
+
+#[test]
+fn axis_key_enlarge_limits_to_string() {
+    assert_eq!(
+        AxisKey::EnlargeLimits(EnlargeLimits::False).to_string(),
+        String::from("enlargelimits=false")
+    );
+    assert_eq!(
+        AxisKey::EnlargeLimits(EnlargeLimits::Rel(0.1)).to_string(),
+        String::from("enlargelimits=0.1")
+    );
+}
+
+#[test]
+fn axis_key_enlarge_x_limits_to_string() {
+    assert_eq!(
+        AxisKey::EnlargeXLimits(EnlargeLimits::Abs(5.0)).to_string(),
+        String::from("enlarge x limits={abs=5}")
+    );
+    assert_eq!(
+        AxisKey::EnlargeXLimits(EnlargeLimits::True).to_string(),
+        String::from("enlarge x limits=true")
+    );
+}
+
+#[test]
+fn axis_key_enlarge_y_limits_to_string() {
+    assert_eq!(
+        AxisKey::EnlargeYLimits(EnlargeLimits::Abs(0.5)).to_string(),
+        String::from("enlarge y limits={abs=0.5}")
+    );
+    assert_eq!(
+        AxisKey::EnlargeYLimits(EnlargeLimits::Rel(0.2)).to_string(),
+        String::from("enlarge y limits=0.2")
+    );
+}
+
+#[test]
+fn axis_key_clip_limits_to_string() {
+    assert_eq!(
+        AxisKey::ClipLimits(false).to_string(),
+        String::from("clip limits=false")
+    );
+    assert_eq!(
+        AxisKey::ClipLimits(true).to_string(),
+        String::from("clip limits=true")
+    );
+}
+
+#[test]
+fn axis_key_max_space_between_ticks_to_string() {
+    assert_eq!(
+        AxisKey::MaxSpaceBetweenTicks(Length::Pt(20.0)).to_string(),
+        String::from("max space between ticks=20pt")
+    );
+}
+
+#[test]
+fn axis_key_try_min_ticks_to_string() {
+    assert_eq!(
+        AxisKey::TryMinTicks(4).to_string(),
+        String::from("try min ticks=4")
+    );
+}
+
 #[test]
 fn axis_key_y_label_to_string() {
     assert_eq!(
@@ -87,6 +733,7 @@ fn axis_key_y_mode_to_string() {
 fn axis_new() {
     let axis = Axis::new();
     assert!(axis.plots.is_empty());
+    assert!(axis.plots_3d.is_empty());
     assert!(axis.keys.is_empty());
 }
 
@@ -114,6 +761,190 @@ fn axis_set_y_label() {
     assert!(matches!(axis.keys[0], AxisKey::YLabel(_)));
 }
 
+#[test]
+fn axis_set_z_label() {
+    let mut axis = Axis::new();
+    axis.set_z_label("Something");
+    assert_eq!(axis.keys.len(), 1);
+    assert!(matches!(axis.keys[0], AxisKey::ZLabel(_)));
+}
+
+#[test]
+fn axis_set_view() {
+    let mut axis = Axis::new();
+    axis.set_view(0.0, 90.0);
+    axis.set_view(25.0, 30.0);
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(axis.keys[0].to_string(), String::from("view={25}{30}"));
+}
+
+#[test]
+fn axis_set_labels() {
+    let mut axis = Axis::new();
+    axis.set_labels("X", "Y");
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(axis.keys[0].to_string(), String::from("xlabel={X}"));
+    assert_eq!(axis.keys[1].to_string(), String::from("ylabel={Y}"));
+}
+
+#[test]
+fn axis_set_title_and_labels() {
+    let mut axis = Axis::new();
+    axis.set_title_and_labels("T", "X", String::from("Y"));
+    assert_eq!(axis.keys.len(), 3);
+    assert_eq!(axis.keys[0].to_string(), String::from("title={T}"));
+    assert_eq!(axis.keys[1].to_string(), String::from("xlabel={X}"));
+    assert_eq!(axis.keys[2].to_string(), String::from("ylabel={Y}"));
+}
+
+#[test]
+fn axis_set_dashed_grid() {
+    let mut axis = Axis::new();
+    axis.set_dashed_grid();
+    axis.set_dashed_grid();
+    assert_eq!(axis.keys.len(), 3);
+    assert_eq!(axis.keys[0].to_string(), String::from("grid=both"));
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("major grid style={dashed, gray!40}")
+    );
+    assert_eq!(
+        axis.keys[2].to_string(),
+        String::from("minor grid style={dotted, gray!20}")
+    );
+}
+
+#[test]
+fn merge_style_options() {
+    assert_eq!(merge_style(None, "font", "\\tiny"), "font=\\tiny");
+    assert_eq!(
+        merge_style(Some(&String::from("rotate=90")), "font", "\\tiny"),
+        "rotate=90, font=\\tiny"
+    );
+    assert_eq!(
+        merge_style(
+            Some(&String::from("font=\\large,at={(0,1)}, anchor=south")),
+            "font",
+            "\\tiny"
+        ),
+        "at={(0,1)}, anchor=south, font=\\tiny"
+    );
+}
+
+#[test]
+fn axis_set_tick_label_font() {
+    let mut axis = Axis::new();
+    axis.set_tick_label_font("\\large");
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("tick label style={font=\\large}")
+    );
+
+    axis.add_key(AxisKey::TickLabelStyle(String::from("rotate=45")));
+    axis.set_tick_label_font("\\footnotesize");
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("tick label style={rotate=45, font=\\footnotesize}")
+    );
+}
+
+#[test]
+fn axis_set_x_tick_label_font() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::XTickLabelStyle(String::from("rotate=45")));
+    axis.set_x_tick_label_font("\\footnotesize");
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("x tick label style={rotate=45, font=\\footnotesize}")
+    );
+}
+
+#[test]
+fn axis_set_y_tick_label_font() {
+    let mut axis = Axis::new();
+    axis.set_y_tick_label_font("\\footnotesize");
+    axis.set_x_tick_label_font("\\tiny");
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("y tick label style={font=\\footnotesize}")
+    );
+}
+
+#[test]
+fn axis_set_title_offset() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::TitleStyle(String::from("font=\\bfseries")));
+    axis.set_title_offset(Length::Pt(5.0));
+    axis.set_title_offset(Length::Mm(2.0));
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("title style={font=\\bfseries, yshift=2mm}")
+    );
+}
+
+#[test]
+fn axis_set_axis_box_size() {
+    let mut axis = Axis::new();
+    axis.set_axis_box_size(Length::Cm(6.0), Length::Cm(4.0));
+    axis.set_axis_box_size(Length::Cm(8.0), Length::Mm(50.0));
+    assert_eq!(axis.keys.len(), 3);
+    assert_eq!(axis.keys[0].to_string(), String::from("width=8cm"));
+    assert_eq!(axis.keys[1].to_string(), String::from("height=50mm"));
+    assert_eq!(
+        axis.keys[2].to_string(),
+        String::from("scale only axis=true")
+    );
+}
+
+#[test]
+fn axis_set_ticks_from_data() {
+    let mut first = Plot2D::new();
+    first.push((3.0, 0.0));
+    first.push((1.0, 0.0));
+    let mut second = Plot2D::new();
+    second.push((2.0, 0.0));
+    second.push((3.0, 0.0));
+    second.push((f64::NAN, 0.0));
+    let mut axis = Axis::new().with_plot(first).with_plot(second);
+    axis.set_ticks_from_data();
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(axis.keys[0].to_string(), String::from("xtick={1,2,3}"));
+
+    let mut plot = Plot2D::new();
+    for x in 0..(2 * MAX_DATA_TICKS + 1) {
+        plot.push((x as f64, 0.0));
+    }
+    axis.plots = vec![plot];
+    axis.set_ticks_from_data();
+    assert_eq!(axis.keys.len(), 1);
+    match &axis.keys[0] {
+        AxisKey::XTick(ticks) => {
+            assert!(ticks.len() <= MAX_DATA_TICKS);
+            assert_eq!(ticks[0], 0.0);
+            assert_eq!(ticks[1], 3.0);
+        }
+        _ => panic!("expected xtick key"),
+    }
+
+    let mut axis = Axis::new().with_plot(Plot2D::new());
+    axis.set_ticks_from_data();
+    assert!(axis.keys.is_empty());
+}
+
+#[test]
+fn axis_set_samples() {
+    let mut axis = Axis::new();
+    axis.set_samples(50);
+    axis.set_samples(100);
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(axis.keys[0].to_string(), String::from("samples=100"));
+}
+
 #[test]
 fn axis_add_key() {
     let mut axis = Axis::new();
@@ -158,6 +989,26 @@ fn axis_add_key() {
     assert_eq!(axis.keys[3].to_string(), String::from("xmode=log"));
 }
 
+#[test]
+fn axis_with_key() {
+    let axis = Axis::new()
+        .with_key(AxisKey::YMode(Scale::Log))
+        .with_key(AxisKey::XMode(Scale::Log))
+        .with_key(AxisKey::YMode(Scale::Normal));
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(axis.keys[0].to_string(), String::from("xmode=log"));
+    assert_eq!(axis.keys[1].to_string(), String::from("ymode=normal"));
+}
+
+#[test]
+fn axis_with_plot() {
+    let axis = Axis::new()
+        .with_plot(Plot2D::new())
+        .with_key(AxisKey::YMode(Scale::Log));
+    assert_eq!(axis.plots.len(), 1);
+    assert_eq!(axis.keys.len(), 1);
+}
+
 #[test]
 fn axis_to_string() {
     let mut axis = Axis::new();
@@ -184,5 +1035,341 @@ fn axis_to_string() {
     plot.add_key(PlotKey::XError(ErrorCharacter::Absolute));
     plot.add_key(PlotKey::XErrorDirection(ErrorDirection::Both));
     axis.plots.push(plot);
-    assert_eq!(axis.to_string(), "\\begin{axis}[\n\tymode=log,\n\txmode=log,\n]\n\t\\addplot[] coordinates {\n\t};\n\t\\addplot[\n\t\terror bars/x explicit,\n\t\terror bars/x dir=both,\n\t] coordinates {\n\t\t(1,-1)\t+- (0,5)\n\t\t(1,-1)\n\t};\n\\end{axis}");
+    assert_eq!(axis.to_string(), "\\begin{axis}[\n\tymode=log,\n\txmode=log,\n]\n\t\\addplot[] coordinates {\n\t};\n\t\\addplot[\n\t\terror bars/x explicit,\n\t\terror bars/x dir=both,\n\t\terror bars/y explicit,\n\t\terror bars/y dir=both,\n\t] coordinates {\n\t\t(1,-1)\t+- (0,5)\n\t\t(1,-1)\n\t};\n\\end{axis}");
+}
+
+#[test]
+fn axis_3d_to_string() {
+    let mut axis = Axis::new();
+    axis.set_z_label("$z$");
+    axis.set_view(25.0, 30.0);
+    assert!(!axis.is_3d());
+    assert_eq!(axis.to_string(), "\\begin{axis}\n\\end{axis}");
+
+    axis.add_key(AxisKey::Title(String::from("Surface")));
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\ttitle={Surface},\n]\n\\end{axis}"
+    );
+
+    let mut plot = Plot3D::new();
+    plot.push((1.0, 2.0, 3.0));
+    axis.plots.push(Plot2D::new());
+    axis.plots_3d.push(plot);
+    assert!(axis.is_3d());
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\tzlabel={$z$},\n\tview={25}{30},\n\ttitle={Surface},\n]\n\t\\addplot[] coordinates {\n\t};\n\t\\addplot3[] coordinates {\n\t\t(1,2,3)\n\t};\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_set_background_image() {
+    let mut axis = Axis::new();
+    axis.plots.push(Plot2D::new());
+    axis.set_background_image("/images/photo.png", (0.0, 640.0, -1.5, 480.0));
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}\n\t\\addplot graphics[xmin=0, xmax=640, ymin=-1.5, ymax=480] {/images/photo.png};\n\t\\addplot[] coordinates {\n\t};\n\\end{axis}"
+    );
+
+    axis.set_background_image("photo.png", (0.0, 1.0, 0.0, 1.0));
+    let (path, _) = axis.background_image.as_ref().unwrap();
+    let path = std::path::Path::new(path);
+    assert!(path.is_absolute());
+    assert!(path.ends_with("photo.png"));
+}
+
+#[test]
+fn axis_legend_to_string() {
+    let mut axis = Axis::new();
+    axis.plots.push(Plot2D::new());
+    assert!(!axis.to_string().contains("\\addlegendentry"));
+
+    let mut first = Plot2D::new();
+    first.set_legend_entry("$a, b$");
+    let mut third = Plot2D::new();
+    third.set_legend_entry("c");
+    axis.plots = vec![first, Plot2D::new(), third, Plot2D::new()];
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}\n\t\\addplot[] coordinates {\n\t};\n\t\\addlegendentry{$a, b$}\n\t\\addplot[] coordinates {\n\t};\n\t\\addplot[] coordinates {\n\t};\n\t\\addlegendentry{c}\n\t\\addplot[] coordinates {\n\t};\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_plot_3d_legend_entry() {
+    let surface = Plot3D::new()
+        .with_key(PlotKey::Type3D(Type3D::Surf))
+        .with_legend_entry("surface");
+    let forgotten = Plot3D::new()
+        .with_key(PlotKey::ForgetPlot)
+        .with_legend_entry("ignored");
+    let axis = Axis::new().with_plot_3d(surface).with_plot_3d(forgotten);
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}\n\t\\addplot3[\n\t\tsurf,\n\t\tarea legend,\n\t] coordinates {\n\t};\n\t\\addlegendentry{surface}\n\t\\addplot3[\n\t\tforget plot,\n\t] coordinates {\n\t};\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_set_limits() {
+    let mut axis = Axis::new();
+    axis.set_x_limits(Some(-1.0), None);
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\txmin=-1,\n]\n\\end{axis}"
+    );
+
+    axis.set_x_limits(None, Some(0.5));
+    axis.set_y_limits(Some(1e-3), Some(1e3));
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\txmax=0.5,\n\tymin=0.001,\n\tymax=1000,\n]\n\\end{axis}"
+    );
+
+    axis.set_y_limits(None, None);
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\txmax=0.5,\n]\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_prepare_for_bars() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::YMin(-1.0));
+    axis.prepare_for_bars();
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\tybar,\n\tenlarge x limits=0.15,\n\tymin=0,\n]\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_set_width_height() {
+    let mut axis = Axis::new();
+    axis.set_width(Length::Cm(5.0));
+    axis.set_width(Length::TextWidth(0.8));
+    axis.set_height(Length::Pt(150.0));
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\twidth=0.8\\textwidth,\n\theight=150pt,\n]\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_add_identity_line() {
+    let mut axis = Axis::new().with_plot(Plot2D::new());
+    axis.add_identity_line();
+    assert_eq!(axis.plots.len(), 1);
+
+    let mut plot = Plot2D::new();
+    plot.push((-1.0, 4.0));
+    plot.push((f64::NAN, 10.0));
+    plot.push((2.0, f64::INFINITY));
+    plot.set_legend_entry("data");
+    axis.plots.push(plot);
+    axis.add_identity_line();
+    assert_eq!(axis.plots.len(), 3);
+
+    let line = &axis.plots[2];
+    assert_eq!((line.coordinates[0].x, line.coordinates[0].y), (-1.0, -1.0));
+    assert_eq!((line.coordinates[1].x, line.coordinates[1].y), (10.0, 10.0));
+    assert_eq!(
+        line.to_string(),
+        "\t\\addplot[\n\t\tdashed,\n\t\tcolor={gray},\n\t\tforget plot,\n\t] coordinates {\n\t\t(-1,-1)\n\t\t(10,10)\n\t};"
+    );
+    assert!(axis
+        .to_string()
+        .contains("\t\t(2,inf)\n\t};\n\t\\addlegendentry{data}\n\t\\addplot[\n\t\tdashed,"));
+    assert_eq!(axis.to_string().matches("\\addlegendentry").count(), 1);
+}
+
+#[test]
+fn axis_legend_forget_plot() {
+    let mut reference = Plot2D::new().with_key(PlotKey::ForgetPlot);
+    reference.set_legend_entry("ignored");
+    let mut data = Plot2D::new();
+    data.set_legend_entry("data");
+    let axis = Axis::new().with_plot(reference).with_plot(data);
+    assert!(!axis.to_string().contains("\\addlegendentry{ignored}"));
+    assert!(axis
+        .to_string()
+        .ends_with("\t\\addlegendentry{data}\n\\end{axis}"));
+}
+
+#[test]
+fn axis_with_legend_entry() {
+    let axis = Axis::new()
+        .with_plot(Plot2D::new().with_legend_entry("first"))
+        .with_plot(Plot2D::new())
+        .with_plot(Plot2D::new().with_legend_entry("third"));
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}\n\t\\addplot[] coordinates {\n\t};\n\t\\addlegendentry{first}\n\t\\addplot[] coordinates {\n\t};\n\t\\addplot[] coordinates {\n\t};\n\t\\addlegendentry{third}\n\\end{axis}"
+    );
+}
+
+#[test]
+fn fill_between_options_to_string() {
+    assert_eq!(FillBetweenOptions::default().to_string(), String::from(""));
+    let options = FillBetweenOptions {
+        fill: Some(Color::Rgb(1, 2, 3)),
+        opacity: Some(0.25),
+    };
+    assert_eq!(
+        options.to_string(),
+        String::from("fill={rgb,255:red,1;green,2;blue,3}, fill opacity=0.25")
+    );
+}
+
+#[test]
+fn axis_add_fill_between() {
+    let mut axis = Axis::new()
+        .with_plot(Plot2D::new().with_key(PlotKey::NamePath(String::from("upper"))))
+        .with_plot(Plot2D::new());
+    axis.add_fill_between(
+        1,
+        0,
+        FillBetweenOptions {
+            opacity: Some(0.5),
+            ..Default::default()
+        },
+    );
+    axis.add_fill_between(1, 0, Default::default());
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}\n\t\\addplot[\n\t\tname path=upper,\n\t] coordinates {\n\t};\n\t\\addplot[\n\t\tname path=plot1,\n\t] coordinates {\n\t};\n\t\\addplot[fill opacity=0.5] fill between[of=plot1 and upper];\n\t\\addplot[] fill between[of=plot1 and upper];\n\\end{axis}"
+    );
+    assert_eq!(axis.libraries(), vec![Library::FillBetween]);
+}
+
+#[test]
+#[should_panic(expected = "cannot fill between plot 2 of an axis with 2 plots")]
+fn axis_add_fill_between_out_of_range() {
+    let mut axis = Axis::new()
+        .with_plot(Plot2D::new())
+        .with_plot(Plot2D::new());
+    axis.add_fill_between(0, 2, Default::default());
+}
+
+#[test]
+fn axis_set_y_label_horizontal() {
+    let mut axis = Axis::new();
+    axis.set_y_label_horizontal("$y$");
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\tylabel={$y$},\n\tylabel style={rotate=-90, at={(axis description cs:0,1.05)}, anchor=south},\n]\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_lock_aspect() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::EnlargeXLimits(EnlargeLimits::Rel(0.15)));
+    axis.add_key(AxisKey::EnlargeLimits(EnlargeLimits::True));
+    axis.lock_aspect();
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\taxis equal=true,\n\taxis equal image=false,\n\tenlargelimits=false,\n]\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_offset_axis_lines() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::XAxisLineStyle(String::from("thick, yshift=-1pt")));
+    axis.offset_axis_lines(Length::Pt(5.0));
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\tseparate axis lines=true,\n\tx axis line style={thick, yshift=-5pt},\n\ty axis line style={xshift=-5pt},\n]\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_set_title_and_labels_text() {
+    let mut axis = Axis::new();
+    axis.set_title_text("50% of x_1 & #2");
+    axis.set_x_label_text("$x$");
+    axis.set_y_label_text("a^b~{c}");
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\ttitle={50\\% of x\\_1 \\& \\#2},\n\txlabel={\\$x\\$},\n\tylabel={a\\textasciicircum{}b\\textasciitilde{}\\{c\\}},\n]\n\\end{axis}"
+    );
+
+    axis.set_title("$y = x^2$");
+    assert!(axis.to_string().contains("\ttitle={$y = x^2$},"));
+}
+
+#[test]
+fn axis_set_tick_distance() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::XTick(vec![0.0, 1.0]));
+    axis.set_x_tick_distance(2.0);
+    axis.set_y_tick_distance(0.5);
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\txtick distance=2,\n\tytick distance=0.5,\n]\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_validate_stacked_bars() {
+    let bars = |values: &[(f64, f64)]| -> Plot2D { values.iter().copied().collect() };
+    let mut axis = Axis::new()
+        .with_plot(bars(&[(1.0, 2.0), (2.0, 3.0)]))
+        .with_plot(bars(&[(1.0, 1.0), (2.0, 4.0)]))
+        .with_plot(bars(&[(1.0, 1.0), (3.0, 4.0)]))
+        .with_plot(bars(&[(5.0, 5.0)]).with_key(PlotKey::ForgetPlot));
+    let mut warnings = Vec::new();
+    axis.validate(0, &mut warnings);
+    assert!(warnings.is_empty());
+
+    axis.add_key(AxisKey::BarStacking(Stacking::Stacked));
+    axis.plots[1].add_key(PlotKey::Type2D(Type2D::YBar {
+        bar_width: 0.5,
+        bar_shift: 0.25,
+    }));
+    axis.validate(0, &mut warnings);
+    assert_eq!(
+        warnings,
+        vec![
+            ValidationWarning::MisalignedStackedBars { axis: 0, plot: 1 },
+            ValidationWarning::MisalignedStackedBars { axis: 0, plot: 2 },
+        ]
+    );
+}
+
+#[test]
+fn axis_validate_stacked_bars_forgotten_first_plot() {
+    let reference: Plot2D = [(0.0, 1.0)].into_iter().collect();
+    let mut bars: Plot2D = [(1.0, 2.0), (2.0, 3.0)].into_iter().collect();
+    bars.add_key(PlotKey::Type2D(Type2D::YBar {
+        bar_width: 0.5,
+        bar_shift: 0.25,
+    }));
+    let axis = Axis::new()
+        .with_key(AxisKey::BarStacking(Stacking::Stacked))
+        .with_plot(reference.with_key(PlotKey::ForgetPlot))
+        .with_plot(bars);
+
+    let mut warnings = Vec::new();
+    axis.validate(0, &mut warnings);
+    assert_eq!(
+        warnings,
+        vec![ValidationWarning::MisalignedStackedBars { axis: 0, plot: 1 }]
+    );
+}
+
+#[test]
+fn axis_stacked_bars_legend_follows_stack() {
+    let mut axis = Axis::new().with_key(AxisKey::BarStacking(Stacking::StackedReversed));
+    for name in ["bottom", "middle", "top"] {
+        axis.plots.push(Plot2D::new().with_legend_entry(name));
+    }
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\tybar stacked, reverse legend,\n]\n\t\\addplot[] coordinates {\n\t};\n\t\\addlegendentry{bottom}\n\t\\addplot[] coordinates {\n\t};\n\t\\addlegendentry{middle}\n\t\\addplot[] coordinates {\n\t};\n\t\\addlegendentry{top}\n\\end{axis}"
+    );
 }
@@ -0,0 +1,77 @@
+use std::fmt;
+
+/// Color of an element in a figure.
+///
+/// The named variants are the colors that are always available in LaTeX's
+/// `xcolor` package. The [`Color::Custom`] variant is provided for any other
+/// color expression, and will be written verbatim.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::color::Color;
+///
+/// assert_eq!(Color::Blue.to_string(), "blue");
+/// assert_eq!(Color::Rgb(12, 34, 56).to_string(), "rgb,255:red,12;green,34;blue,56");
+/// assert_eq!(Color::Custom(String::from("red!50!black")).to_string(), "red!50!black");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Color {
+    Black,
+    Blue,
+    Brown,
+    Cyan,
+    DarkGray,
+    Gray,
+    Green,
+    LightGray,
+    Lime,
+    Magenta,
+    Olive,
+    Orange,
+    Pink,
+    Purple,
+    Red,
+    Teal,
+    Violet,
+    White,
+    Yellow,
+    /// Color given by its red, green, and blue components.
+    Rgb(u8, u8, u8),
+    /// Custom color expression e.g. `blue!40`. This will be written verbatim.
+    Custom(String),
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Black => write!(f, "black"),
+            Color::Blue => write!(f, "blue"),
+            Color::Brown => write!(f, "brown"),
+            Color::Cyan => write!(f, "cyan"),
+            Color::DarkGray => write!(f, "darkgray"),
+            Color::Gray => write!(f, "gray"),
+            Color::Green => write!(f, "green"),
+            Color::LightGray => write!(f, "lightgray"),
+            Color::Lime => write!(f, "lime"),
+            Color::Magenta => write!(f, "magenta"),
+            Color::Olive => write!(f, "olive"),
+            Color::Orange => write!(f, "orange"),
+            Color::Pink => write!(f, "pink"),
+            Color::Purple => write!(f, "purple"),
+            Color::Red => write!(f, "red"),
+            Color::Teal => write!(f, "teal"),
+            Color::Violet => write!(f, "violet"),
+            Color::White => write!(f, "white"),
+            Color::Yellow => write!(f, "yellow"),
+            Color::Rgb(red, green, blue) => {
+                write!(f, "rgb,255:red,{red};green,{green};blue,{blue}")
+            }
+            Color::Custom(color) => write!(f, "{color}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
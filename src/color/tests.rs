@@ -0,0 +1,79 @@
+use super::*;
+
+// This test is here only to let us know if we added an enum variant
+// but we forgot to add unit tests for it
+//
+// If this fails, it is because you added a new variant.
+// Please do the following:
+// 1) Add a unit test for the new variant you added (see examples below).
+// 2) AFTER doing (1), add the new variant to the match.
+#[test]
+fn colors_tested() {
+    let color = Color::Black;
+    match color {
+        Color::Black => (),
+        Color::Blue => (),
+        Color::Brown => (),
+        Color::Cyan => (),
+        Color::DarkGray => (),
+        Color::Gray => (),
+        Color::Green => (),
+        Color::LightGray => (),
+        Color::Lime => (),
+        Color::Magenta => (),
+        Color::Olive => (),
+        Color::Orange => (),
+        Color::Pink => (),
+        Color::Purple => (),
+        Color::Red => (),
+        Color::Teal => (),
+        Color::Violet => (),
+        Color::White => (),
+        Color::Yellow => (),
+        Color::Rgb(_, _, _) => (),
+        Color::Custom(_) => (),
+    }
+}
+
+#[test]
+fn color_named_to_string() {
+    assert_eq!(Color::Black.to_string(), String::from("black"));
+    assert_eq!(Color::Blue.to_string(), String::from("blue"));
+    assert_eq!(Color::Brown.to_string(), String::from("brown"));
+    assert_eq!(Color::Cyan.to_string(), String::from("cyan"));
+    assert_eq!(Color::DarkGray.to_string(), String::from("darkgray"));
+    assert_eq!(Color::Gray.to_string(), String::from("gray"));
+    assert_eq!(Color::Green.to_string(), String::from("green"));
+    assert_eq!(Color::LightGray.to_string(), String::from("lightgray"));
+    assert_eq!(Color::Lime.to_string(), String::from("lime"));
+    assert_eq!(Color::Magenta.to_string(), String::from("magenta"));
+    assert_eq!(Color::Olive.to_string(), String::from("olive"));
+    assert_eq!(Color::Orange.to_string(), String::from("orange"));
+    assert_eq!(Color::Pink.to_string(), String::from("pink"));
+    assert_eq!(Color::Purple.to_string(), String::from("purple"));
+    assert_eq!(Color::Red.to_string(), String::from("red"));
+    assert_eq!(Color::Teal.to_string(), String::from("teal"));
+    assert_eq!(Color::Violet.to_string(), String::from("violet"));
+    assert_eq!(Color::White.to_string(), String::from("white"));
+    assert_eq!(Color::Yellow.to_string(), String::from("yellow"));
+}
+
+#[test]
+fn color_rgb_to_string() {
+    assert_eq!(
+        Color::Rgb(12, 34, 56).to_string(),
+        String::from("rgb,255:red,12;green,34;blue,56")
+    );
+    assert_eq!(
+        Color::Rgb(0, 255, 0).to_string(),
+        String::from("rgb,255:red,0;green,255;blue,0")
+    );
+}
+
+#[test]
+fn color_custom_to_string() {
+    assert_eq!(
+        Color::Custom(String::from("blue!40")).to_string(),
+        String::from("blue!40")
+    );
+}
@@ -18,10 +18,8 @@
 //! ```no_run
 //! use pgfplots::axis::plot::Plot2D;
 //!
-//! let mut plot = Plot2D::new();
-//! plot.coordinates = (-100..100)
-//!     .into_iter()
-//!     .map(|i| (f64::from(i), f64::from(i*i)).into())
+//! let plot: Plot2D = (-100..100)
+//!     .map(|i| (f64::from(i), f64::from(i*i)))
 //!     .collect();
 //!
 //! # #[cfg(feature = "inclusive")]
@@ -40,15 +38,22 @@ use crate::axis::{
     AxisKey,
 };
 
-use crate::axis::Axis;
+use crate::axis::{group::GroupPlot, Axis, GridStyle};
+use crate::color::Color;
 use std::fmt;
 use std::io::Write;
 use thiserror::Error;
 
 const OUT_NAME: &str = "figure";
+// Number of lines of the log kept in ShowPdfError::CompilationFailed.
+const LOG_TAIL_LINES: usize = 20;
 
 /// Axis environment inside a [`Picture`].
 pub mod axis;
+/// Colors of the elements in a figure.
+pub mod color;
+/// Units of measurement used by keys.
+pub mod units;
 
 /// The error type returned when showing a figure fails.
 #[derive(Debug, Error)]
@@ -63,6 +68,61 @@ pub enum ShowPdfError {
     /// Failed to open file.
     #[error("failed to open file: {0}")]
     Open(#[from] opener::OpenError),
+    /// The installed LaTeX engine (or `dvisvgm`, when converting to SVG)
+    /// exited with an error e.g. due to a missing package.
+    #[error(
+        "{program} failed with exit code {} (see {}):\n{log}",
+        .code.map_or_else(|| String::from("none"), |code| code.to_string()),
+        .build_dir.display()
+    )]
+    CompilationFailed {
+        /// Program that failed e.g. `pdflatex` or `dvisvgm`.
+        program: String,
+        /// Exit code of the program, if it was not terminated by a signal.
+        code: Option<i32>,
+        /// Last lines of the log file written by the engine, or the error
+        /// output of `dvisvgm`.
+        log: String,
+        /// Directory with the source, log, and any other file written by the
+        /// engine. It is left intact to inspect the failure.
+        build_dir: std::path::PathBuf,
+    },
+    /// The installed engine does not produce the requested output format e.g.
+    /// PDF data was requested from [`Engine::Latex`], which produces DVI.
+    #[error("{engine} does not produce {extension} output")]
+    UnsupportedEngine {
+        /// Engine that was asked for the output.
+        engine: Engine,
+        /// Extension of the requested output format.
+        extension: &'static str,
+    },
+}
+
+/// Common mistake found by [`Picture::validate`]. The indices locate the
+/// offending element in [`Picture::axes`] and [`Axis::plots`]. The axes of
+/// [`Picture::group_plots`] are numbered after [`Picture::axes`], in order.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationWarning {
+    /// The plot has no coordinates.
+    #[error("plot {plot} in axis {axis} has no coordinates")]
+    EmptyPlot { axis: usize, plot: usize },
+    /// The coordinate cannot be drawn in a logarithmic axis because it is not
+    /// positive.
+    #[error("coordinate {coordinate} of plot {plot} in axis {axis} is not positive in a logarithmic axis")]
+    NonPositiveLogCoordinate {
+        axis: usize,
+        plot: usize,
+        coordinate: usize,
+    },
+    /// The plot is a bar plot with bars of zero width.
+    #[error("plot {plot} in axis {axis} has bars of zero width")]
+    ZeroBarWidth { axis: usize, plot: usize },
+    /// The plot is part of a stack of bars (see [`AxisKey::BarStacking`]), but
+    /// its bars do not line up with those of the first plot: either its *x*
+    /// values differ, or its bars are shifted.
+    #[error("plot {plot} in axis {axis} is not aligned with the stacked bars")]
+    MisalignedStackedBars { axis: usize, plot: usize },
 }
 
 pub enum Compiler {
@@ -75,16 +135,94 @@ pub enum Compiler {
 #[non_exhaustive]
 pub enum Engine {
     PdfLatex,
+    /// Produces DVI instead of PDF output.
+    Latex,
+    /// Supports Unicode input and system fonts e.g. with `fontspec`.
+    XeLatex,
+    /// Supports Unicode input and system fonts e.g. with `fontspec`.
+    LuaLatex,
 }
 
 impl fmt::Display for Engine {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::PdfLatex => write!(f, "pdflatex"),
+            Self::Latex => write!(f, "latex"),
+            Self::XeLatex => write!(f, "xelatex"),
+            Self::LuaLatex => write!(f, "lualatex"),
+        }
+    }
+}
+
+impl Engine {
+    /// Return the extension of the output file produced by the engine.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::Engine;
+    ///
+    /// assert_eq!(Engine::PdfLatex.output_extension(), "pdf");
+    /// assert_eq!(Engine::Latex.output_extension(), "dvi");
+    /// ```
+    pub fn output_extension(&self) -> &'static str {
+        match self {
+            Self::PdfLatex | Self::XeLatex | Self::LuaLatex => "pdf",
+            Self::Latex => "dvi",
         }
     }
 }
 
+/// Where and under which name the files of a compiled [`Picture`] are
+/// written. See [`Picture::show_with_options`].
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::CompileOptions;
+///
+/// let options = CompileOptions {
+///     out_dir: Some("figures".into()),
+///     job_name: Some(String::from("growth")),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CompileOptions {
+    /// Directory of the output files, created if missing. Existing files with
+    /// the same job name are overwritten. If this is `None`, a new directory
+    /// unique to each compilation is created in [`std::env::temp_dir()`], so
+    /// pictures can be compiled in parallel.
+    pub out_dir: Option<std::path::PathBuf>,
+    /// Base name (without extension) of the output files. Defaults to
+    /// `figure`.
+    pub job_name: Option<String>,
+    /// Print the output of an installed engine to the terminal instead of
+    /// discarding it. The end of the log is included in
+    /// [`ShowPdfError::CompilationFailed`] either way.
+    pub verbose: bool,
+    /// Pass the `-shell-escape` flag to an installed engine, which lets it run
+    /// external programs e.g. gnuplot. See [`Picture::requires_shell_escape`].
+    /// Tectonic does not support shell escape, so this is ignored there.
+    pub shell_escape: bool,
+}
+
+impl CompileOptions {
+    // Return the directory of the output files, creating it if needed.
+    fn create_out_dir(&self) -> std::io::Result<std::path::PathBuf> {
+        match &self.out_dir {
+            Some(out_dir) => {
+                std::fs::create_dir_all(out_dir)?;
+                Ok(out_dir.clone())
+            }
+            None => temp_output_dir(),
+        }
+    }
+    fn job_name(&self) -> &str {
+        self.job_name.as_deref().unwrap_or(OUT_NAME)
+    }
+}
+
 /// Ti*k*Z options passed to the [`Picture`] environment.
 ///
 /// The most commonly used key-value pairs are variants of the [`PictureKey`]
@@ -107,6 +245,67 @@ impl fmt::Display for PictureKey {
     }
 }
 
+/// House style shared by every axis of a [`Picture`].
+///
+/// Fields that are [`None`] (or empty) leave the default PGFPlots style
+/// unchanged. Apply it with [`Picture::apply_theme`].
+#[derive(Clone, Debug, Default)]
+pub struct Theme {
+    /// Colors cycled through by consecutive plots in an axis.
+    pub colors: Vec<Color>,
+    /// Grid lines drawn in every axis.
+    pub grid: Option<GridStyle>,
+    /// Font of every axis e.g. `\small`.
+    pub font: Option<String>,
+    /// Style of the ticks e.g. `black, thin`. This will be written verbatim
+    /// inside the `{...}` of the style.
+    pub tick_style: Option<String>,
+}
+
+/// PGFPlots or Ti*k*Z library loaded in the preamble of the standalone
+/// document.
+///
+/// Libraries needed by the axes and plots of a [`Picture`] are added
+/// automatically; use [`Picture::add_library`] for any other library.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::Library;
+///
+/// assert_eq!(Library::FillBetween.to_string(), "\\usepgfplotslibrary{fillbetween}");
+/// assert_eq!(
+///     Library::Tikz(String::from("arrows.meta")).to_string(),
+///     "\\usetikzlibrary{arrows.meta}"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Library {
+    /// PGFPlots library to draw patches e.g. triangles.
+    PatchPlots,
+    /// PGFPlots library to fill the region between two plots.
+    FillBetween,
+    /// PGFPlots library to arrange axes in a grid.
+    GroupPlots,
+    /// Any other PGFPlots library, loaded with `\usepgfplotslibrary`.
+    PgfPlots(String),
+    /// Ti*k*Z library, loaded with `\usetikzlibrary`.
+    Tikz(String),
+}
+
+impl fmt::Display for Library {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Library::PatchPlots => write!(f, "\\usepgfplotslibrary{{patchplots}}"),
+            Library::FillBetween => write!(f, "\\usepgfplotslibrary{{fillbetween}}"),
+            Library::GroupPlots => write!(f, "\\usepgfplotslibrary{{groupplots}}"),
+            Library::PgfPlots(name) => write!(f, "\\usepgfplotslibrary{{{name}}}"),
+            Library::Tikz(name) => write!(f, "\\usetikzlibrary{{{name}}}"),
+        }
+    }
+}
+
 /// Picture environment.
 ///
 /// Creating a [`Picture`] is equivalent to the Ti*k*Z graphics environment:
@@ -122,11 +321,26 @@ impl fmt::Display for PictureKey {
 #[derive(Clone, Debug, Default)]
 pub struct Picture {
     keys: Vec<PictureKey>,
+    background: Option<Color>,
+    document_class: Option<String>,
+    document_class_options: Option<String>,
+    libraries: Vec<Library>,
+    preamble: Vec<String>,
     pub axes: Vec<Axis>,
+    /// Grids of axes, drawn after [`Picture::axes`].
+    pub group_plots: Vec<GroupPlot>,
 }
 
 impl fmt::Display for Picture {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_environment(f, false)
+    }
+}
+
+impl Picture {
+    // Write the whole `tikzpicture` environment. If `overlays` is true, each
+    // plot with an overlay is wrapped in a Beamer `\only<i->`.
+    fn write_environment<W: fmt::Write>(&self, f: &mut W, overlays: bool) -> fmt::Result {
         write!(f, "\\begin{{tikzpicture}}")?;
         // If there are keys, print one per line. It makes it easier for a
         // human later to find keys if they are divided by lines.
@@ -139,8 +353,21 @@ impl fmt::Display for Picture {
         }
         writeln!(f)?;
 
+        // Indent each axis environment one level inside the picture, so the
+        // nesting of environments is visible in the generated code.
         for axis in self.axes.iter() {
-            writeln!(f, "{axis}")?;
+            let mut environment = String::new();
+            axis.write_environment(&mut environment, overlays)?;
+            for line in environment.lines() {
+                writeln!(f, "\t{line}")?;
+            }
+        }
+        for group in self.group_plots.iter() {
+            let mut environment = String::new();
+            group.write_environment(&mut environment, overlays)?;
+            for line in environment.lines() {
+                writeln!(f, "\t{line}")?;
+            }
         }
 
         write!(f, "\\end{{tikzpicture}}")?;
@@ -181,6 +408,241 @@ impl Picture {
         }
         self.keys.push(key);
     }
+    /// Append an axis environment to the picture.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.add_axis(Axis::new());
+    /// assert_eq!(picture.axes.len(), 1);
+    /// ```
+    pub fn add_axis(&mut self, axis: Axis) {
+        self.axes.push(axis);
+    }
+    /// Return a mutable reference to the axis at `index`, or `None` if the
+    /// picture has no such axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.add_axis(Axis::new());
+    /// if let Some(axis) = picture.axis_mut(0) {
+    ///     axis.set_title("Growth");
+    /// }
+    /// assert!(picture.axis_mut(1).is_none());
+    /// ```
+    pub fn axis_mut(&mut self, index: usize) -> Option<&mut Axis> {
+        self.axes.get_mut(index)
+    }
+    /// Set the background color of the standalone figure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{color::Color, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.set_background(Color::Rgb(240, 240, 240));
+    /// ```
+    pub fn set_background(&mut self, color: Color) {
+        self.background = Some(color);
+    }
+    /// Set the document class of [`Picture::standalone_string`] instead of
+    /// `standalone`, the default. An empty string restores the default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::Picture;
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.set_document_class("article");
+    /// assert!(picture.standalone_string().starts_with("\\documentclass{article}"));
+    /// ```
+    pub fn set_document_class(&mut self, class: &str) {
+        self.document_class = if class.is_empty() {
+            None
+        } else {
+            Some(String::from(class))
+        };
+    }
+    /// Set the options of the document class (`standalone` by default) e.g.
+    /// `border=2pt` to control the margin around the standalone figure. An
+    /// empty string removes the options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::Picture;
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.set_document_class_options("border=2pt");
+    /// assert!(picture
+    ///     .standalone_string()
+    ///     .starts_with("\\documentclass[border=2pt]{standalone}"));
+    /// ```
+    pub fn set_document_class_options(&mut self, options: &str) {
+        self.document_class_options = if options.is_empty() {
+            None
+        } else {
+            Some(String::from(options))
+        };
+    }
+    /// Set the lines written verbatim between `\usepackage{pgfplots}` (and
+    /// any needed library) and `\begin{document}` in
+    /// [`Picture::standalone_string`] e.g. to load other packages. This
+    /// replaces every previous line, including the lines added by
+    /// [`Picture::apply_theme`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::Picture;
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.set_preamble(vec![String::from("\\usepackage{siunitx}")]);
+    /// assert!(picture
+    ///     .standalone_string()
+    ///     .contains("\\usepackage{pgfplots}\n\\usepackage{siunitx}\n\\begin{document}"));
+    /// ```
+    pub fn set_preamble(&mut self, lines: Vec<String>) {
+        self.preamble = lines;
+    }
+    /// Apply a house style to every axis of the picture. This adds the cycle
+    /// list and `every axis` style of the `theme` to the preamble of the
+    /// standalone document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::GridStyle, color::Color, Picture, Theme};
+    ///
+    /// let theme = Theme {
+    ///     colors: vec![Color::Blue, Color::Orange],
+    ///     grid: Some(GridStyle::Major),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.apply_theme(&theme);
+    /// ```
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        let mut style = Vec::new();
+        if !theme.colors.is_empty() {
+            let colors: Vec<String> = theme
+                .colors
+                .iter()
+                .map(|color| format!("{{color={{{color}}}}}"))
+                .collect();
+            self.push_preamble(format!(
+                "\\pgfplotscreateplotcyclelist{{theme}}{{{}}}",
+                colors.join(",")
+            ));
+            style.push(String::from("cycle list name=theme"));
+        }
+        if let Some(grid) = theme.grid {
+            style.push(format!("grid={grid}"));
+        }
+        if let Some(font) = &theme.font {
+            style.push(format!("font={font}"));
+        }
+        if let Some(tick_style) = &theme.tick_style {
+            style.push(format!("tick style={{{tick_style}}}"));
+        }
+        if !style.is_empty() {
+            self.push_preamble(format!(
+                "\\pgfplotsset{{every axis/.append style={{{}}}}}",
+                style.join(", ")
+            ));
+        }
+    }
+    /// Load a library in the preamble of [`Picture::standalone_string`],
+    /// unless it is already loaded. Libraries needed by the axes and plots of
+    /// the picture are loaded automatically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{Library, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.add_library(Library::Tikz(String::from("arrows.meta")));
+    /// assert!(picture
+    ///     .standalone_string()
+    ///     .contains("\\usetikzlibrary{arrows.meta}\n"));
+    /// ```
+    pub fn add_library(&mut self, library: Library) {
+        if !self.libraries.contains(&library) {
+            self.libraries.push(library);
+        }
+    }
+    // Add a line to the preamble of the standalone document, unless it is
+    // already there.
+    fn push_preamble(&mut self, line: String) {
+        if !self.preamble.contains(&line) {
+            self.preamble.push(line);
+        }
+    }
+    /// Move all the axes, group plots, and keys of `other` into the picture.
+    /// Keys of `other` overwrite any mutually exclusive key of the picture,
+    /// while [`PictureKey::Custom`] keys are always kept. Libraries and
+    /// preamble lines of `other` are added unless the picture already has
+    /// them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.axes.push(Axis::new());
+    /// let mut other = Picture::new();
+    /// other.axes.push(Axis::new());
+    ///
+    /// picture.append(other);
+    /// assert_eq!(picture.axes.len(), 2);
+    /// ```
+    pub fn append(&mut self, other: Picture) {
+        for key in other.keys {
+            self.add_key(key);
+        }
+        for library in other.libraries {
+            self.add_library(library);
+        }
+        for line in other.preamble {
+            self.push_preamble(line);
+        }
+        self.axes.extend(other.axes);
+        self.group_plots.extend(other.group_plots);
+    }
+    /// Return the picture environment for a Beamer frame, revealing plots one
+    /// slide at a time. Each plot with an overlay (see
+    /// [`Plot2D::set_overlay`]) is wrapped in `\only<i->`, so it appears from
+    /// slide `i` onwards. Plots without an overlay appear on every slide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::{plot::Plot2D, Axis}, Picture};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.set_overlay(2);
+    /// let mut picture = Picture::new();
+    /// picture.add_axis(Axis::new().with_plot(plot));
+    /// assert!(picture.to_beamer_overlays().contains("\\only<2->{"));
+    /// ```
+    pub fn to_beamer_overlays(&self) -> String {
+        let mut overlays = String::new();
+        self.write_environment(&mut overlays, true)
+            .expect("writing to a string should not fail");
+        overlays
+    }
     /// Return a [`String`] with valid LaTeX code that generates a standalone
     /// PDF with the picture environment.
     ///
@@ -207,7 +669,133 @@ impl Picture {
     /// picture.standalone_string());
     /// ```
     pub fn standalone_string(&self) -> String {
-        format!("\\documentclass{{standalone}}\n\\usepackage{{pgfplots}}\n\\begin{{document}}\n{}\n\\end{{document}}", self)
+        let class = self.document_class.as_deref().unwrap_or("standalone");
+        let mut string = match &self.document_class_options {
+            Some(options) => format!("\\documentclass[{options}]{{{class}}}\n"),
+            None => format!("\\documentclass{{{class}}}\n"),
+        };
+        string.push_str("\\usepackage{pgfplots}\n");
+        let mut libraries: Vec<&Library> = Vec::new();
+        let required: Vec<Library> = self
+            .axes
+            .iter()
+            .flat_map(Axis::libraries)
+            .chain(self.group_plots.iter().flat_map(GroupPlot::libraries))
+            .collect();
+        for library in self.libraries.iter().chain(required.iter()) {
+            if !libraries.contains(&library) {
+                libraries.push(library);
+            }
+        }
+        for library in libraries {
+            string.push_str(&format!("{library}\n"));
+        }
+        for line in self.preamble.iter() {
+            string.push_str(&format!("{line}\n"));
+        }
+        string.push_str("\\begin{document}\n");
+        // The standalone class crops the page around the picture, so coloring
+        // the page colors exactly the background of the figure.
+        if let Some(color) = &self.background {
+            string.push_str(&format!("\\pagecolor{{{color}}}\n"));
+        }
+        string.push_str(&format!("{self}\n\\end{{document}}"));
+        string
+    }
+    /// Return a [`String`] with LaTeX code of a `figure` environment with the
+    /// picture, a caption, and a label. Special characters in the `caption`
+    /// are escaped, while the `label` is written verbatim. Including this in
+    /// a document requires `\usepackage{pgfplots}` in its preamble.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::Picture;
+    ///
+    /// let mut picture = Picture::new();
+    /// assert_eq!(
+    /// r#"\begin{figure}
+    /// \centering
+    /// \begin{tikzpicture}
+    /// \end{tikzpicture}
+    /// \caption{Growth of 50\% per year}
+    /// \label{fig:growth}
+    /// \end{figure}"#,
+    /// picture.figure_string("Growth of 50% per year", "fig:growth"));
+    /// ```
+    pub fn figure_string(&self, caption: &str, label: &str) -> String {
+        format!(
+            "\\begin{{figure}}\n\\centering\n{}\n\\caption{{{}}}\n\\label{{{}}}\n\\end{{figure}}",
+            self,
+            latex_escape(caption),
+            label
+        )
+    }
+    /// Check the picture for common mistakes that would otherwise only show up
+    /// after compiling it e.g. empty plots, non-positive coordinates in a
+    /// logarithmic axis, or bars of zero width. The axes of
+    /// [`Picture::group_plots`] are checked too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::{plot::Plot2D, Axis}, Picture, ValidationWarning};
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.axes.push(Axis::new().with_plot(Plot2D::new()));
+    ///
+    /// assert_eq!(
+    ///     picture.validate(),
+    ///     Err(vec![ValidationWarning::EmptyPlot { axis: 0, plot: 0 }])
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ValidationWarning>> {
+        let mut warnings = Vec::new();
+        // Axes of the group plots are numbered after the other axes.
+        let axes = self
+            .axes
+            .iter()
+            .chain(self.group_plots.iter().flat_map(|group| group.axes.iter()));
+        for (index, axis) in axes.enumerate() {
+            axis.validate(index, &mut warnings);
+        }
+
+        if warnings.is_empty() {
+            Ok(())
+        } else {
+            Err(warnings)
+        }
+    }
+    /// Whether compiling the picture requires shell escape i.e. the
+    /// `-shell-escape` flag of the LaTeX engine. This is the case for plots
+    /// computed by gnuplot e.g. with a `contour gnuplot` [`PlotKey::Custom`].
+    ///
+    /// Only the [`PlotKey::Custom`] keys of the plots are inspected, for the
+    /// word `gnuplot`; none of the typed keys need shell escape. Custom axis
+    /// keys, the preamble, and other raw LaTeX are not inspected.
+    ///
+    /// Set [`CompileOptions::shell_escape`] to pass the flag to
+    /// [`Compiler::Installed`]. Tectonic does not support shell escape at all;
+    /// such pictures cannot be compiled with it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::{plot::{Plot3D, PlotKey}, Axis}, Picture};
+    ///
+    /// let plot = Plot3D::new().with_key(PlotKey::Custom(String::from("contour gnuplot")));
+    ///
+    /// let mut picture = Picture::new();
+    /// assert!(!picture.requires_shell_escape());
+    /// picture.axes.push(Axis::new().with_plot_3d(plot));
+    /// assert!(picture.requires_shell_escape());
+    /// ```
+    pub fn requires_shell_escape(&self) -> bool {
+        self.axes.iter().any(Axis::requires_shell_escape)
+            || self
+                .group_plots
+                .iter()
+                .any(GroupPlot::requires_shell_escape)
     }
     /// Show the picture as a standalone PDF. This will create a file in the
     /// location returned by [`std::env::temp_dir()`] and open it with the
@@ -223,53 +811,279 @@ impl Picture {
     /// ```
     #[cfg(feature = "inclusive")]
     pub fn show(&self) -> Result<(), ShowPdfError> {
-        let pdf_data = tectonic::latex_to_pdf(self.standalone_string())?;
-        let mut path = temp_output_dir()?;
-        path.push(OUT_NAME);
-        path.set_extension("pdf");
+        let path = self.compile_tectonic(&CompileOptions::default())?;
+        opener::open(path)?;
+
+        Ok(())
+    }
+    /// Show the picture as a standalone document compiled with `builder`, and
+    /// return the path of the opened file. The file is created in a new
+    /// directory inside the location returned by [`std::env::temp_dir()`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{Compiler, Engine, Picture};
+    ///
+    /// let picture = Picture::new();
+    /// let path = picture.show_with(&Compiler::Installed(Engine::PdfLatex))?;
+    /// println!("figure written to {}", path.display());
+    /// # Ok::<(), pgfplots::ShowPdfError>(())
+    /// ```
+    pub fn show_with(&self, builder: &Compiler) -> Result<std::path::PathBuf, ShowPdfError> {
+        self.show_with_options(builder, &CompileOptions::default())
+    }
+    /// Same as [`Picture::show_with`], but the output directory and the base
+    /// name of the files are taken from `options`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{CompileOptions, Compiler, Engine, Picture};
+    ///
+    /// let picture = Picture::new();
+    /// let options = CompileOptions {
+    ///     out_dir: Some("build".into()),
+    ///     job_name: Some(String::from("growth")),
+    ///     verbose: true,
+    ///     ..Default::default()
+    /// };
+    /// // Opens `build/growth.pdf`
+    /// picture.show_with_options(&Compiler::Installed(Engine::PdfLatex), &options)?;
+    /// # Ok::<(), pgfplots::ShowPdfError>(())
+    /// ```
+    pub fn show_with_options(
+        &self,
+        builder: &Compiler,
+        options: &CompileOptions,
+    ) -> Result<std::path::PathBuf, ShowPdfError> {
+        let out_file = match builder {
+            #[cfg(feature = "inclusive")]
+            Compiler::Tectonic => self.compile_tectonic(options)?,
+            Compiler::Installed(engine) => {
+                let out_dir = self.compile_installed(engine, options)?;
+                // the resulting .pdf (or .dvi)
+                output_file(&out_dir, options.job_name(), engine.output_extension())
+            }
+        };
+        opener::open(&out_file)?;
+
+        Ok(out_file)
+    }
+    /// Return the PDF data of the picture as a standalone document, without
+    /// opening it. This is useful e.g. in headless environments, or to write
+    /// the figure to a different location. An installed engine must produce
+    /// PDF output, otherwise [`ShowPdfError::UnsupportedEngine`] is returned
+    /// without compiling (e.g. for [`Engine::Latex`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{Compiler, Engine, Picture};
+    ///
+    /// let picture = Picture::new();
+    /// let pdf = picture.to_pdf_bytes(&Compiler::Installed(Engine::PdfLatex))?;
+    /// std::fs::write("figure.pdf", pdf)?;
+    /// # Ok::<(), pgfplots::ShowPdfError>(())
+    /// ```
+    pub fn to_pdf_bytes(&self, compiler: &Compiler) -> Result<Vec<u8>, ShowPdfError> {
+        match compiler {
+            #[cfg(feature = "inclusive")]
+            Compiler::Tectonic => Ok(tectonic::latex_to_pdf(self.standalone_string())?),
+            Compiler::Installed(engine) => self.read_installed_output(engine, "pdf"),
+        }
+    }
+    /// Compile the picture as a standalone PDF, and write it to `path` without
+    /// opening it. The `.pdf` extension is appended to the `path` if it does
+    /// not have it. Missing parent directories are created, and an existing
+    /// file is overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{Compiler, Engine, Picture};
+    ///
+    /// let picture = Picture::new();
+    /// // Written to `figures/growth.pdf`
+    /// picture.save_to("figures/growth", &Compiler::Installed(Engine::PdfLatex))?;
+    /// # Ok::<(), pgfplots::ShowPdfError>(())
+    /// ```
+    pub fn save_to<P: AsRef<std::path::Path>>(
+        &self,
+        path: P,
+        compiler: &Compiler,
+    ) -> Result<(), ShowPdfError> {
+        let path = with_pdf_extension(path.as_ref());
+        let pdf_data = self.to_pdf_bytes(compiler)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, pdf_data)?;
+
+        Ok(())
+    }
+    /// Return a standalone SVG image of the picture. The picture is compiled
+    /// to PDF (or DVI) with the installed `engine`, and then converted with
+    /// `dvisvgm` (which must also be installed). If `text_as_paths` is `true`,
+    /// text is converted to paths so the SVG does not depend on any font. If
+    /// `dvisvgm` fails, its error output is returned in
+    /// [`ShowPdfError::CompilationFailed`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{Engine, Picture};
+    ///
+    /// let picture = Picture::new();
+    /// let svg = picture.to_standalone_svg_string(&Engine::PdfLatex, true)?;
+    /// # Ok::<(), pgfplots::ShowPdfError>(())
+    /// ```
+    pub fn to_standalone_svg_string(
+        &self,
+        engine: &Engine,
+        text_as_paths: bool,
+    ) -> Result<String, ShowPdfError> {
+        let out_dir = self.compile_installed(engine, &CompileOptions::default())?;
+        let out_file = format!("{OUT_NAME}.{}", engine.output_extension());
+        // dvisvgm reads DVI files by default
+        let from_pdf = engine.output_extension() == "pdf";
+        let svg = convert_to_svg("dvisvgm", &out_file, from_pdf, text_as_paths, &out_dir)?;
+        std::fs::remove_dir_all(out_dir)?;
+
+        Ok(svg)
+    }
+    /// Return the DVI data of the picture as a standalone document, which can
+    /// be further processed with e.g. `dvips` or `dvisvgm`. The `engine` must
+    /// be installed, and produce DVI output (e.g. [`Engine::Latex`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{Engine, Picture};
+    ///
+    /// let picture = Picture::new();
+    /// let dvi = picture.to_dvi_bytes(&Engine::Latex)?;
+    /// # Ok::<(), pgfplots::ShowPdfError>(())
+    /// ```
+    pub fn to_dvi_bytes(&self, engine: &Engine) -> Result<Vec<u8>, ShowPdfError> {
+        self.read_installed_output(engine, "dvi")
+    }
+    // Compile the standalone document with a pre-installed LaTeX engine, and
+    // return the content of the output file with the given extension. The
+    // engine must produce that extension. The output directory is removed
+    // unless the compilation fails.
+    fn read_installed_output(
+        &self,
+        engine: &Engine,
+        extension: &'static str,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        if engine.output_extension() != extension {
+            return Err(ShowPdfError::UnsupportedEngine {
+                engine: engine.clone(),
+                extension,
+            });
+        }
+        let out_dir = self.compile_installed(engine, &CompileOptions::default())?;
+        let data = std::fs::read(output_file(&out_dir, OUT_NAME, extension));
+        std::fs::remove_dir_all(out_dir)?;
+        Ok(data?)
+    }
+    // Compile the standalone document with Tectonic, and return the path of
+    // the resulting PDF file.
+    #[cfg(feature = "inclusive")]
+    fn compile_tectonic(
+        &self,
+        options: &CompileOptions,
+    ) -> Result<std::path::PathBuf, ShowPdfError> {
+        let pdf_data = self.to_pdf_bytes(&Compiler::Tectonic)?;
+        let path = output_file(&options.create_out_dir()?, options.job_name(), "pdf");
 
         let mut file = std::fs::File::create(&path)?;
         file.write_all(&pdf_data)?;
 
-        opener::open(&path)?;
+        Ok(path)
+    }
+    // Compile the standalone document with a pre-installed LaTeX engine, and
+    // return the directory which contains the output files.
+    fn compile_installed(
+        &self,
+        engine: &Engine,
+        options: &CompileOptions,
+    ) -> Result<std::path::PathBuf, ShowPdfError> {
+        // generate output dir in /tmp (on linux) unless one is given
+        let out_dir = options.create_out_dir()?;
+        // generate the .tex source file
+        let source_file = output_file(&out_dir, options.job_name(), "tex");
+        // write the code to the source file (otherwise args can get too large)
+        let mut file = std::fs::File::create(&source_file)?;
+        file.write_all(self.standalone_string().as_bytes())?;
+        // compile the figure with the pre-installed latex compiler
+        compile_figure_with(
+            &engine.to_string(),
+            source_file.file_name().unwrap(),
+            &out_dir,
+            options,
+        )?;
+        Ok(out_dir)
+    }
+}
 
-        Ok(())
+// Return the path with a `.pdf` extension appended, unless it already has
+// it.
+fn with_pdf_extension(path: &std::path::Path) -> std::path::PathBuf {
+    if path.extension().is_some_and(|extension| extension == "pdf") {
+        path.to_path_buf()
+    } else {
+        let mut path = path.as_os_str().to_owned();
+        path.push(".pdf");
+        path.into()
     }
+}
 
-    pub fn show_with(&self, builder: &Compiler) -> Result<(), ShowPdfError> {
-        match builder {
-            #[cfg(feature = "inclusive")]
-            Compiler::Tectonic => self.show(),
-            Compiler::Installed(engine) => {
-                // generate output dir in /tmp (on linux)
-                let out_dir = temp_output_dir()?;
-                // generate the .tex source file
-                let mut source_file = out_dir.clone();
-                source_file.push(OUT_NAME);
-                source_file.set_extension("tex");
-                // write the code to the source file (otherwise args can get too large)
-                let mut file = std::fs::File::create(&source_file)?;
-                file.write_all(self.standalone_string().as_bytes())?;
-                // compile the figure with the pre-installed latex compiler
-                compile_figure_with(
-                    &engine.to_string(),
-                    source_file.file_name().unwrap(),
-                    &out_dir,
-                )?;
-                // open the resulting .pdf
-                let mut out_file = out_dir;
-                out_file.push(OUT_NAME);
-                out_file.set_extension("pdf");
-                opener::open(out_file)?;
-                Ok(())
+/// Escape the characters with a special meaning in LaTeX (`\`, `{`, `}`,
+/// `$`, `&`, `#`, `%`, `_`, `^` and `~`), so `text` is typeset verbatim e.g.
+/// in a title or a label supplied by a user.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::latex_escape;
+///
+/// assert_eq!(latex_escape("50% of x_1 & y"), "50\\% of x\\_1 \\& y");
+/// ```
+pub fn latex_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
             }
+            _ => escaped.push(c),
         }
     }
+    escaped
 }
 
+// Return the path of the output file with the given base name and extension.
+// The extension is appended, so a base name with dots is kept whole.
+fn output_file(out_dir: &std::path::Path, job_name: &str, extension: &str) -> std::path::PathBuf {
+    out_dir.join(format!("{job_name}.{extension}"))
+}
+
+// Create a new output directory in the temporary directory. The process id and
+// a counter make it unique, so concurrent compilations do not clobber each
+// other's files.
 fn temp_output_dir() -> std::io::Result<std::path::PathBuf> {
+    static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+    let count = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
     let mut path = std::env::temp_dir();
-    path.push("output");
+    path.push(format!("pgfplots-{}-{count}", std::process::id()));
+    // Left over by an earlier process with the same id.
     if path.exists() {
         std::fs::remove_dir_all(&path)?;
     }
@@ -277,22 +1091,83 @@ fn temp_output_dir() -> std::io::Result<std::path::PathBuf> {
     Ok(path)
 }
 
+// All engines share the same command line flags.
 fn compile_figure_with(
     engine: &str,
     source: &std::ffi::OsStr,
     out_dir: &std::path::Path,
+    options: &CompileOptions,
 ) -> Result<(), ShowPdfError> {
-    std::process::Command::new(engine)
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
-        .arg("-interaction=batchmode")
+    let job_name = options.job_name();
+    let mut command = std::process::Command::new(engine);
+    if options.verbose {
+        // batchmode would silence the terminal output of the engine
+        command.arg("-interaction=nonstopmode");
+    } else {
+        command
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .arg("-interaction=batchmode");
+    }
+    let status = command
         .arg("-halt-on-error")
-        .arg("-jobname=figure")
+        .args(options.shell_escape.then_some("-shell-escape"))
+        .arg(format!("-jobname={job_name}"))
         .arg(source)
         .current_dir(out_dir)
         .status()?;
+    if !status.success() {
+        return Err(ShowPdfError::CompilationFailed {
+            program: engine.to_string(),
+            code: status.code(),
+            log: log_tail(out_dir, job_name),
+            build_dir: out_dir.to_path_buf(),
+        });
+    }
     Ok(())
 }
 
+// Convert the `input` file in `out_dir` to SVG with `converter` (i.e.
+// `dvisvgm`), and return the SVG document. The directory is left intact if the
+// conversion fails, with the error output of the converter as the log.
+fn convert_to_svg(
+    converter: &str,
+    input: &str,
+    from_pdf: bool,
+    text_as_paths: bool,
+    out_dir: &std::path::Path,
+) -> Result<String, ShowPdfError> {
+    let mut command = std::process::Command::new(converter);
+    if from_pdf {
+        command.arg("--pdf");
+    }
+    command.arg("--stdout");
+    if text_as_paths {
+        command.arg("--no-fonts");
+    }
+    let output = command.arg(input).current_dir(out_dir).output()?;
+    if !output.status.success() {
+        return Err(ShowPdfError::CompilationFailed {
+            program: converter.to_string(),
+            code: output.status.code(),
+            log: String::from_utf8_lossy(&output.stderr)
+                .trim_end()
+                .to_string(),
+            build_dir: out_dir.to_path_buf(),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// Return the last lines of the log file written by the engine in `out_dir`,
+// which usually contain the reason of a failed compilation. The log is empty if
+// the file cannot be read.
+fn log_tail(out_dir: &std::path::Path, job_name: &str) -> String {
+    let log = std::fs::read(output_file(out_dir, job_name, "log")).unwrap_or_default();
+    let log = String::from_utf8_lossy(&log);
+    let lines: Vec<&str> = log.lines().collect();
+    lines[lines.len().saturating_sub(LOG_TAIL_LINES)..].join("\n")
+}
+
 #[cfg(test)]
 mod tests;
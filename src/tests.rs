@@ -1,5 +1,8 @@
 use super::*;
-use crate::axis::plot::Plot2D;
+use crate::axis::{
+    plot::{Plot2D, PlotKey},
+    AxisKey,
+};
 
 // This test is here only to let us know if we added an enum variant
 // but we forgot to add unit tests for it
@@ -16,6 +19,22 @@ fn picture_keys_tested() {
     }
 }
 
+#[test]
+fn engine_to_string() {
+    assert_eq!(Engine::PdfLatex.to_string(), String::from("pdflatex"));
+    assert_eq!(Engine::Latex.to_string(), String::from("latex"));
+    assert_eq!(Engine::XeLatex.to_string(), String::from("xelatex"));
+    assert_eq!(Engine::LuaLatex.to_string(), String::from("lualatex"));
+}
+
+#[test]
+fn engine_output_extension() {
+    assert_eq!(Engine::PdfLatex.output_extension(), "pdf");
+    assert_eq!(Engine::Latex.output_extension(), "dvi");
+    assert_eq!(Engine::XeLatex.output_extension(), "pdf");
+    assert_eq!(Engine::LuaLatex.output_extension(), "pdf");
+}
+
 #[test]
 fn picture_key_custom_to_string() {
     assert_eq!(
@@ -44,6 +63,29 @@ fn picture_add_key() {
     assert_eq!(picture.keys[1].to_string(), String::from("random"));
 }
 
+#[test]
+fn picture_append() {
+    let mut picture = Picture::new();
+    picture.add_key(PictureKey::Custom(String::from("baseline")));
+    let mut axis = Axis::new();
+    axis.set_title("First");
+    picture.axes.push(axis);
+
+    let mut other = Picture::new();
+    other.add_key(PictureKey::Custom(String::from("baseline")));
+    let mut axis = Axis::new();
+    axis.set_title("Second");
+    other.axes.push(axis);
+
+    picture.append(other);
+    assert_eq!(picture.axes.len(), 2);
+    assert!(picture.axes[0].to_string().contains("title={First}"));
+    assert!(picture.axes[1].to_string().contains("title={Second}"));
+    assert_eq!(picture.keys.len(), 2);
+    assert_eq!(picture.keys[0].to_string(), String::from("baseline"));
+    assert_eq!(picture.keys[1].to_string(), String::from("baseline"));
+}
+
 #[test]
 fn picture_standalone_string() {
     let picture = Picture::new();
@@ -58,6 +100,179 @@ fn picture_standalone_string() {
     );
 }
 
+#[test]
+fn picture_add_axis() {
+    let mut picture = Picture::new();
+    picture.add_axis(Axis::new());
+    picture.add_axis(Axis::new().with_plot(Plot2D::new()));
+    assert_eq!(picture.axes.len(), 2);
+
+    picture.axis_mut(1).unwrap().set_title("Second");
+    assert!(picture.axes[1].to_string().contains("title={Second}"));
+    assert!(picture.axis_mut(2).is_none());
+}
+
+#[test]
+fn picture_set_document_class_options() {
+    let mut picture = Picture::new();
+    picture.set_document_class_options("border=5pt");
+    picture.set_document_class_options("border=2pt");
+    assert_eq!(
+        picture.standalone_string(),
+        r#"\documentclass[border=2pt]{standalone}
+\usepackage{pgfplots}
+\begin{document}
+\begin{tikzpicture}
+\end{tikzpicture}
+\end{document}"#
+    );
+
+    picture.set_document_class_options("");
+    assert!(picture
+        .standalone_string()
+        .starts_with("\\documentclass{standalone}\n"));
+}
+
+#[test]
+fn picture_set_document_class() {
+    let mut picture = Picture::new();
+    picture.set_document_class("article");
+    picture.set_document_class_options("a4paper");
+    assert!(picture
+        .standalone_string()
+        .starts_with("\\documentclass[a4paper]{article}\n\\usepackage{pgfplots}\n"));
+
+    picture.set_document_class("");
+    assert!(picture
+        .standalone_string()
+        .starts_with("\\documentclass[a4paper]{standalone}\n"));
+}
+
+#[test]
+fn picture_set_preamble() {
+    let mut picture = Picture::new();
+    picture.apply_theme(&Theme {
+        font: Some(String::from("\\small")),
+        ..Default::default()
+    });
+    picture.add_library(Library::FillBetween);
+    picture.set_preamble(vec![
+        String::from("\\usepackage{siunitx}"),
+        String::from("\\sisetup{detect-all}"),
+    ]);
+    assert_eq!(
+        picture.standalone_string(),
+        r#"\documentclass{standalone}
+\usepackage{pgfplots}
+\usepgfplotslibrary{fillbetween}
+\usepackage{siunitx}
+\sisetup{detect-all}
+\begin{document}
+\begin{tikzpicture}
+\end{tikzpicture}
+\end{document}"#
+    );
+}
+
+#[test]
+fn picture_set_background() {
+    let mut picture = Picture::new();
+    picture.set_background(Color::Red);
+    picture.set_background(Color::Rgb(1, 2, 3));
+    assert_eq!(
+        picture.standalone_string(),
+        r#"\documentclass{standalone}
+\usepackage{pgfplots}
+\begin{document}
+\pagecolor{rgb,255:red,1;green,2;blue,3}
+\begin{tikzpicture}
+\end{tikzpicture}
+\end{document}"#
+    );
+}
+
+#[test]
+fn picture_figure_string() {
+    let mut picture = Picture::new();
+    picture.axes.push(Axis::new());
+    assert_eq!(
+        picture.figure_string("A $5 & 10% plot_1", "fig:plot"),
+        "\\begin{figure}\n\\centering\n\\begin{tikzpicture}\n\t\\begin{axis}\n\t\\end{axis}\n\\end{tikzpicture}\n\\caption{A \\$5 \\& 10\\% plot\\_1}\n\\label{fig:plot}\n\\end{figure}"
+    );
+}
+
+#[test]
+fn temp_output_dir_unique() {
+    let first = temp_output_dir().unwrap();
+    let second = temp_output_dir().unwrap();
+    assert_ne!(first, second);
+    assert!(first.is_dir() && second.is_dir());
+    std::fs::remove_dir_all(first).unwrap();
+    std::fs::remove_dir_all(second).unwrap();
+}
+
+#[test]
+fn compile_options_out_dir() {
+    let out_dir = std::env::temp_dir().join("pgfplots_compile_options/nested");
+    let options = CompileOptions {
+        out_dir: Some(out_dir.clone()),
+        job_name: Some(String::from("growth.v2")),
+        ..Default::default()
+    };
+    assert_eq!(options.create_out_dir().unwrap(), out_dir);
+    assert!(out_dir.is_dir());
+    assert_eq!(
+        output_file(&out_dir, options.job_name(), "pdf"),
+        out_dir.join("growth.v2.pdf")
+    );
+    assert_eq!(CompileOptions::default().job_name(), "figure");
+}
+
+#[test]
+fn with_pdf_extension_appended() {
+    use std::path::{Path, PathBuf};
+
+    assert_eq!(
+        with_pdf_extension(Path::new("out/figure.pdf")),
+        PathBuf::from("out/figure.pdf")
+    );
+    assert_eq!(
+        with_pdf_extension(Path::new("out/figure")),
+        PathBuf::from("out/figure.pdf")
+    );
+    assert_eq!(
+        with_pdf_extension(Path::new("figure.v2")),
+        PathBuf::from("figure.v2.pdf")
+    );
+}
+
+#[test]
+fn latex_escape_special_characters() {
+    assert_eq!(latex_escape("plain text"), "plain text");
+    assert_eq!(
+        latex_escape("\\{}$&#%_^~"),
+        "\\textbackslash{}\\{\\}\\$\\&\\#\\%\\_\\textasciicircum{}\\textasciitilde{}"
+    );
+}
+
+#[test]
+fn latex_escape_each_special_character() {
+    for (c, escaped) in [
+        ("\\", "\\textbackslash{}"),
+        ("{", "\\{"),
+        ("}", "\\}"),
+        ("$", "\\$"),
+        ("&", "\\&"),
+        ("#", "\\#"),
+        ("%", "\\%"),
+        ("_", "\\_"),
+        ("^", "\\textasciicircum{}"),
+        ("~", "\\textasciitilde{}"),
+    ] {
+        assert_eq!(latex_escape(&format!("a{c}b")), format!("a{escaped}b"));
+    }
+}
+
 #[test]
 fn picture_to_string() {
     let mut picture = Picture::new();
@@ -77,12 +292,477 @@ fn picture_to_string() {
     picture.axes.push(axis.clone());
     assert_eq!(
         picture.to_string(),
-        "\\begin{tikzpicture}\n\\begin{axis}\n\\end{axis}\n\\end{tikzpicture}"
+        "\\begin{tikzpicture}\n\t\\begin{axis}\n\t\\end{axis}\n\\end{tikzpicture}"
     );
 
     picture.add_key(PictureKey::Custom(String::from("baseline")));
     picture.add_key(PictureKey::Custom(String::from("scale=2")));
     axis.plots.push(Plot2D::new());
     picture.axes.push(axis.clone());
-    assert_eq!(picture.to_string(), "\\begin{tikzpicture}[\n\tbaseline,\n\tscale=2,\n]\n\\begin{axis}\n\\end{axis}\n\\begin{axis}\n\t\\addplot[] coordinates {\n\t};\n\\end{axis}\n\\end{tikzpicture}");
+    assert_eq!(picture.to_string(), "\\begin{tikzpicture}[\n\tbaseline,\n\tscale=2,\n]\n\t\\begin{axis}\n\t\\end{axis}\n\t\\begin{axis}\n\t\t\\addplot[] coordinates {\n\t\t};\n\t\\end{axis}\n\\end{tikzpicture}");
+
+    picture.axes.truncate(1);
+    picture.keys.clear();
+    picture.axes[0].add_key(AxisKey::Custom(String::from("hide axis")));
+    picture.axes[0].plots.push(Plot2D::new());
+    picture.axes[0].plots[0].add_key(PlotKey::Custom(String::from("dashed")));
+    picture.axes[0].plots[0].coordinates.push((1.0, 2.0).into());
+    assert_eq!(
+        picture.to_string(),
+        "\\begin{tikzpicture}\n\t\\begin{axis}[\n\t\thide axis,\n\t]\n\t\t\\addplot[\n\t\t\tdashed,\n\t\t] coordinates {\n\t\t\t(1,2)\n\t\t};\n\t\\end{axis}\n\\end{tikzpicture}"
+    );
+}
+
+#[test]
+#[ignore = "requires pdflatex and dvisvgm"]
+fn picture_to_standalone_svg_string() {
+    let mut axis = Axis::new();
+    axis.set_title("Text");
+    let mut picture = Picture::new();
+    picture.axes.push(axis);
+
+    let svg = picture
+        .to_standalone_svg_string(&Engine::PdfLatex, true)
+        .unwrap();
+    assert!(svg.contains("<path"));
+    assert!(!svg.contains("<text"));
+}
+
+#[test]
+#[ignore = "requires pdflatex and a PDF viewer"]
+fn picture_show_with() {
+    let mut picture = Picture::new();
+    picture.axes.push(Axis::new());
+
+    let path = picture
+        .show_with(&Compiler::Installed(Engine::PdfLatex))
+        .unwrap();
+    assert!(path.exists());
+    assert_eq!(path.extension().unwrap(), "pdf");
+}
+
+#[test]
+fn picture_to_pdf_bytes_unsupported_engine() {
+    let picture = Picture::new();
+    assert!(matches!(
+        picture.to_pdf_bytes(&Compiler::Installed(Engine::Latex)),
+        Err(ShowPdfError::UnsupportedEngine {
+            engine: Engine::Latex,
+            extension: "pdf"
+        })
+    ));
+    assert!(matches!(
+        picture.to_dvi_bytes(&Engine::PdfLatex),
+        Err(ShowPdfError::UnsupportedEngine {
+            engine: Engine::PdfLatex,
+            extension: "dvi"
+        })
+    ));
+}
+
+#[test]
+#[ignore = "requires pdflatex"]
+fn picture_to_pdf_bytes() {
+    let mut picture = Picture::new();
+    picture.axes.push(Axis::new());
+
+    let pdf = picture
+        .to_pdf_bytes(&Compiler::Installed(Engine::PdfLatex))
+        .unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+}
+
+#[test]
+#[ignore = "requires pdflatex"]
+fn picture_to_pdf_bytes_parallel() {
+    let threads: Vec<_> = (0..4)
+        .map(|i| {
+            std::thread::spawn(move || {
+                let mut axis = Axis::new();
+                axis.set_title(format!("Figure {i}"));
+                let mut picture = Picture::new();
+                picture.axes.push(axis);
+                picture.to_pdf_bytes(&Compiler::Installed(Engine::PdfLatex))
+            })
+        })
+        .collect();
+    for thread in threads {
+        assert!(thread.join().unwrap().unwrap().starts_with(b"%PDF"));
+    }
+}
+
+#[test]
+#[ignore = "requires pdflatex"]
+fn picture_save_to() {
+    let mut picture = Picture::new();
+    picture.axes.push(Axis::new());
+
+    let mut path = std::env::temp_dir();
+    path.push("pgfplots_save_to");
+    path.push("nested");
+    path.push("figure");
+    let compiler = Compiler::Installed(Engine::PdfLatex);
+    picture.save_to(&path, &compiler).unwrap();
+    picture.save_to(&path, &compiler).unwrap();
+
+    path.set_extension("pdf");
+    assert!(std::fs::read(&path).unwrap().starts_with(b"%PDF"));
+}
+
+#[test]
+#[cfg(unix)]
+fn compile_figure_with_failed_status() {
+    let out_dir = std::env::temp_dir().join("pgfplots_compilation_failed");
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let log: Vec<String> = (0..30).map(|i| format!("line {i}")).collect();
+    std::fs::write(out_dir.join("figure.log"), log.join("\n")).unwrap();
+
+    // `false` ignores its arguments and exits with an error.
+    let error = compile_figure_with(
+        "false",
+        std::ffi::OsStr::new("figure.tex"),
+        &out_dir,
+        &CompileOptions::default(),
+    )
+    .unwrap_err();
+    match error {
+        ShowPdfError::CompilationFailed {
+            ref program,
+            code,
+            ref log,
+            ref build_dir,
+        } => {
+            assert_eq!(program, "false");
+            assert_eq!(code, Some(1));
+            assert_eq!(log.lines().count(), LOG_TAIL_LINES);
+            assert!(log.starts_with("line 10\n"));
+            assert!(log.ends_with("line 29"));
+            assert_eq!(build_dir, &out_dir);
+            assert!(build_dir.join("figure.log").exists());
+        }
+        _ => panic!("expected a compilation failure"),
+    }
+    assert!(error.to_string().starts_with(&format!(
+        "false failed with exit code 1 (see {}):\nline 10\n",
+        out_dir.display()
+    )));
+}
+
+#[test]
+#[cfg(unix)]
+fn compile_figure_with_shell_escape() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let out_dir = std::env::temp_dir().join("pgfplots_compilation_shell_escape");
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let engine = out_dir.join("fake-latex");
+    std::fs::write(&engine, "#!/bin/sh\necho \"$@\" > arguments\n").unwrap();
+    std::fs::set_permissions(&engine, std::fs::Permissions::from_mode(0o755)).unwrap();
+    let arguments = || std::fs::read_to_string(out_dir.join("arguments")).unwrap();
+
+    let source = std::ffi::OsStr::new("figure.tex");
+    let mut options = CompileOptions::default();
+    compile_figure_with(engine.to_str().unwrap(), source, &out_dir, &options).unwrap();
+    assert!(!arguments().contains("-shell-escape"));
+
+    options.shell_escape = true;
+    compile_figure_with(engine.to_str().unwrap(), source, &out_dir, &options).unwrap();
+    assert!(arguments().contains("-shell-escape"));
+}
+
+#[test]
+#[cfg(unix)]
+fn compile_figure_with_verbose_failed_status() {
+    let out_dir = std::env::temp_dir().join("pgfplots_verbose_compilation_failed");
+    std::fs::create_dir_all(&out_dir).unwrap();
+    std::fs::write(out_dir.join("broken.log"), "! Undefined control sequence.").unwrap();
+
+    let options = CompileOptions {
+        job_name: Some(String::from("broken")),
+        verbose: true,
+        ..Default::default()
+    };
+    let error = compile_figure_with(
+        "false",
+        std::ffi::OsStr::new("broken.tex"),
+        &out_dir,
+        &options,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        ShowPdfError::CompilationFailed { code: Some(1), ref log, .. }
+            if log == "! Undefined control sequence."
+    ));
+}
+
+#[test]
+#[cfg(unix)]
+fn convert_to_svg_failed_status() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let out_dir = std::env::temp_dir().join("pgfplots_svg_conversion_failed");
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let converter = out_dir.join("fake-dvisvgm");
+    std::fs::write(
+        &converter,
+        "#!/bin/sh\necho \"ERROR: no such file\" >&2\nexit 2\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&converter, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let error = convert_to_svg(
+        converter.to_str().unwrap(),
+        "figure.pdf",
+        true,
+        false,
+        &out_dir,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        ShowPdfError::CompilationFailed { code: Some(2), ref log, ref build_dir, .. }
+            if log == "ERROR: no such file" && build_dir == &out_dir
+    ));
+    assert!(error
+        .to_string()
+        .starts_with(&format!("{} failed with exit code 2", converter.display())));
+    assert!(out_dir.exists());
+}
+
+#[test]
+#[ignore = "requires pdflatex"]
+fn picture_compilation_failed() {
+    let mut axis = Axis::new();
+    axis.set_title("\\undefinedcommand");
+    let mut picture = Picture::new();
+    picture.axes.push(axis);
+
+    let error = picture
+        .to_pdf_bytes(&Compiler::Installed(Engine::PdfLatex))
+        .unwrap_err();
+    assert!(
+        matches!(error, ShowPdfError::CompilationFailed { log, .. } if log.contains("Undefined control sequence"))
+    );
+}
+
+#[test]
+#[ignore = "requires latex"]
+fn picture_to_dvi_bytes() {
+    let mut picture = Picture::new();
+    picture.axes.push(Axis::new());
+
+    let dvi = picture.to_dvi_bytes(&Engine::Latex).unwrap();
+    // Every DVI file starts with the preamble opcode.
+    assert_eq!(dvi[0], 247);
+}
+
+#[test]
+fn picture_requires_shell_escape() {
+    let mut picture = Picture::new();
+    picture.axes.push(Axis::new().with_plot(Plot2D::new()));
+    assert!(!picture.requires_shell_escape());
+
+    let gnuplot = Plot2D::new().with_key(PlotKey::Custom(String::from("raw gnuplot, id=parabola")));
+    picture.axes.push(Axis::new().with_plot(gnuplot));
+    assert!(picture.requires_shell_escape());
+
+    let mut picture = Picture::new();
+    picture
+        .axes
+        .push(
+            Axis::new().with_plot_3d(axis::plot::Plot3D::new().with_key(PlotKey::Custom(
+                String::from("contour gnuplot={levels={0.5, 1}}"),
+            ))),
+        );
+    assert!(picture.requires_shell_escape());
+}
+
+#[test]
+fn picture_validate() {
+    let mut picture = Picture::new();
+    assert_eq!(picture.validate(), Ok(()));
+
+    let mut plot = Plot2D::new();
+    plot.push((1.0, 1.0));
+    plot.push((2.0, 0.0));
+    plot.push((3.0, -1.0));
+    let mut axis = Axis::new().with_plot(plot);
+    picture.axes.push(axis.clone());
+    assert_eq!(picture.validate(), Ok(()));
+
+    axis.add_key(AxisKey::YMode(axis::Scale::Log));
+    axis.plots.push(
+        Plot2D::new().with_key(PlotKey::Type2D(axis::plot::Type2D::YBar {
+            bar_width: 0.0,
+            bar_shift: 0.0,
+        })),
+    );
+    picture.axes.push(axis);
+    assert_eq!(
+        picture.validate(),
+        Err(vec![
+            ValidationWarning::NonPositiveLogCoordinate {
+                axis: 1,
+                plot: 0,
+                coordinate: 1
+            },
+            ValidationWarning::NonPositiveLogCoordinate {
+                axis: 1,
+                plot: 0,
+                coordinate: 2
+            },
+            ValidationWarning::EmptyPlot { axis: 1, plot: 1 },
+            ValidationWarning::ZeroBarWidth { axis: 1, plot: 1 },
+        ])
+    );
+}
+
+#[test]
+fn picture_validate_group_plots() {
+    let mut plot = Plot2D::new();
+    plot.push((1.0, 1.0));
+    let mut picture = Picture::new();
+    picture.axes.push(Axis::new().with_plot(plot.clone()));
+
+    let mut group = axis::group::GroupPlot::new(1, 2);
+    group.axes.push(Axis::new().with_plot(plot));
+    group.axes.push(Axis::new().with_plot(Plot2D::new()));
+    picture.group_plots.push(group);
+    let mut group = axis::group::GroupPlot::new(1, 1);
+    group.axes.push(Axis::new().with_plot(Plot2D::new()));
+    picture.group_plots.push(group);
+
+    assert_eq!(
+        picture.validate(),
+        Err(vec![
+            ValidationWarning::EmptyPlot { axis: 2, plot: 0 },
+            ValidationWarning::EmptyPlot { axis: 3, plot: 0 },
+        ])
+    );
+}
+
+#[test]
+fn picture_apply_theme() {
+    let mut picture = Picture::new();
+    picture.apply_theme(&Theme::default());
+    assert!(picture.preamble.is_empty());
+
+    let theme = Theme {
+        colors: vec![Color::Blue, Color::Rgb(1, 2, 3)],
+        grid: Some(axis::GridStyle::Major),
+        font: Some(String::from("\\small")),
+        tick_style: Some(String::from("black")),
+    };
+    picture.apply_theme(&theme);
+    picture.apply_theme(&theme);
+    assert_eq!(
+        picture.standalone_string(),
+        r#"\documentclass{standalone}
+\usepackage{pgfplots}
+\pgfplotscreateplotcyclelist{theme}{{color={blue}},{color={rgb,255:red,1;green,2;blue,3}}}
+\pgfplotsset{every axis/.append style={cycle list name=theme, grid=major, font=\small, tick style={black}}}
+\begin{document}
+\begin{tikzpicture}
+\end{tikzpicture}
+\end{document}"#
+    );
+
+    let mut other = Picture::new();
+    other.apply_theme(&Theme {
+        grid: Some(axis::GridStyle::Both),
+        ..Default::default()
+    });
+    other.apply_theme(&theme);
+    picture.append(other);
+    assert_eq!(picture.preamble.len(), 3);
+    assert_eq!(
+        picture.preamble[2],
+        String::from("\\pgfplotsset{every axis/.append style={grid=both}}")
+    );
+}
+
+#[test]
+fn picture_standalone_string_libraries() {
+    let triangle = Plot2D::patch_triangles(vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]).unwrap();
+    let mut picture = Picture::new();
+    picture.axes.push(
+        Axis::new()
+            .with_plot(triangle.clone())
+            .with_plot(triangle.clone()),
+    );
+    picture.axes.push(Axis::new().with_plot(triangle));
+    let standalone = picture.standalone_string();
+    assert!(standalone.starts_with(
+        "\\documentclass{standalone}\n\\usepackage{pgfplots}\n\\usepgfplotslibrary{patchplots}\n\\begin{document}\n"
+    ));
+    assert_eq!(standalone.matches("\\usepgfplotslibrary").count(), 1);
+}
+
+#[test]
+fn picture_add_library() {
+    let triangle = Plot2D::patch_triangles(vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]).unwrap();
+    let mut picture = Picture::new();
+    picture.axes.push(Axis::new().with_plot(triangle));
+    picture.add_library(Library::Tikz(String::from("arrows.meta")));
+    picture.add_library(Library::PatchPlots);
+    picture.add_library(Library::Tikz(String::from("arrows.meta")));
+
+    let mut other = Picture::new();
+    other.add_library(Library::PgfPlots(String::from("colorbrewer")));
+    other.add_library(Library::PatchPlots);
+    picture.append(other);
+
+    assert!(picture.standalone_string().starts_with(
+        "\\documentclass{standalone}\n\\usepackage{pgfplots}\n\\usetikzlibrary{arrows.meta}\n\\usepgfplotslibrary{patchplots}\n\\usepgfplotslibrary{colorbrewer}\n\\begin{document}\n"
+    ));
+}
+
+#[test]
+fn picture_group_plots() {
+    let mut group = axis::group::GroupPlot::new(1, 2);
+    let triangle = Plot2D::patch_triangles(vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]).unwrap();
+    group.axes.push(Axis::new().with_plot(triangle));
+    let mut picture = Picture::new();
+    picture.axes.push(Axis::new());
+    picture.group_plots.push(group);
+    assert_eq!(
+        picture.standalone_string(),
+        "\\documentclass{standalone}\n\\usepackage{pgfplots}\n\\usepgfplotslibrary{groupplots}\n\\usepgfplotslibrary{patchplots}\n\\begin{document}\n\\begin{tikzpicture}\n\t\\begin{axis}\n\t\\end{axis}\n\t\\begin{groupplot}[\n\t\tgroup style={group size=2 by 1},\n\t]\n\t\\nextgroupplot\n\t\t\\addplot[\n\t\t\tpatch, patch type=triangle,\n\t\t] coordinates {\n\t\t\t(0,0)\n\t\t\t(1,0)\n\t\t\t(0,1)\n\t\t};\n\t\\end{groupplot}\n\\end{tikzpicture}\n\\end{document}"
+    );
+}
+
+#[test]
+fn picture_standalone_string_fill_between_library() {
+    let mut axis = Axis::new()
+        .with_plot(Plot2D::new())
+        .with_plot(Plot2D::new());
+    axis.add_fill_between(0, 1, Default::default());
+    let mut picture = Picture::new();
+    picture.axes.push(axis);
+    assert!(picture.standalone_string().starts_with(
+        "\\documentclass{standalone}\n\\usepackage{pgfplots}\n\\usepgfplotslibrary{fillbetween}\n\\begin{document}\n"
+    ));
+}
+
+#[test]
+fn picture_to_beamer_overlays() {
+    let mut data = Plot2D::new().with_legend_entry("data");
+    data.push((1.0, 2.0));
+    data.set_overlay(2);
+    let mut fit = Plot2D::new();
+    fit.set_overlay(3);
+    let mut picture = Picture::new();
+    picture.add_axis(
+        Axis::new()
+            .with_plot(Plot2D::new())
+            .with_plot(data)
+            .with_plot(fit),
+    );
+
+    assert_eq!(
+        picture.to_beamer_overlays(),
+        "\\begin{tikzpicture}\n\t\\begin{axis}\n\t\t\\addplot[] coordinates {\n\t\t};\n\t\t\\only<2->{\n\t\t\t\\addplot[] coordinates {\n\t\t\t\t(1,2)\n\t\t\t};\n\t\t\t\\addlegendentry{data}\n\t\t}\n\t\t\\only<3->{\n\t\t\t\\addplot[] coordinates {\n\t\t\t};\n\t\t}\n\t\\end{axis}\n\\end{tikzpicture}"
+    );
+    assert!(!picture.to_string().contains("\\only"));
 }
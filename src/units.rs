@@ -0,0 +1,119 @@
+use std::fmt;
+use std::ops::{Div, Mul, Neg};
+
+/// Length with an explicit LaTeX unit.
+///
+/// Values are written without trailing zeros, so `Length::Cm(1.0)` is written
+/// as `1cm`.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::units::Length;
+///
+/// assert_eq!(Length::Pt(20.0).to_string(), "20pt");
+/// assert_eq!(Length::Cm(1.5).to_string(), "1.5cm");
+/// assert_eq!((Length::TextWidth(0.4) * 2.0).to_string(), "0.8\\textwidth");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Length {
+    /// Length in points (`pt`). This is TeX's default unit.
+    Pt(f64),
+    /// Length in millimeters (`mm`).
+    Mm(f64),
+    /// Length in centimeters (`cm`).
+    Cm(f64),
+    /// Length in inches (`in`).
+    In(f64),
+    /// Length as a fraction of the width of the text in the document.
+    TextWidth(f64),
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Length::Pt(value) => write!(f, "{value}pt"),
+            Length::Mm(value) => write!(f, "{value}mm"),
+            Length::Cm(value) => write!(f, "{value}cm"),
+            Length::In(value) => write!(f, "{value}in"),
+            Length::TextWidth(value) => write!(f, "{value}\\textwidth"),
+        }
+    }
+}
+
+impl From<f64> for Length {
+    /// Conversion from a value in points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::units::Length;
+    ///
+    /// let length: Length = 2.5.into();
+    /// assert_eq!(length, Length::Pt(2.5));
+    /// ```
+    fn from(value: f64) -> Self {
+        Length::Pt(value)
+    }
+}
+
+impl Length {
+    /// Return the length in points, or [`None`] if the length is relative to
+    /// the document (e.g. [`Length::TextWidth`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::units::Length;
+    ///
+    /// assert_eq!(Length::In(1.0).to_pt(), Some(72.27));
+    /// assert!(Length::TextWidth(0.5).to_pt().is_none());
+    /// ```
+    pub fn to_pt(&self) -> Option<f64> {
+        match self {
+            Length::Pt(value) => Some(*value),
+            Length::Mm(value) => Some(value * 72.27 / 25.4),
+            Length::Cm(value) => Some(value * 72.27 / 2.54),
+            Length::In(value) => Some(value * 72.27),
+            Length::TextWidth(_) => None,
+        }
+    }
+    // Apply `f` to the value, keeping the unit.
+    fn map(self, f: impl Fn(f64) -> f64) -> Self {
+        match self {
+            Length::Pt(value) => Length::Pt(f(value)),
+            Length::Mm(value) => Length::Mm(f(value)),
+            Length::Cm(value) => Length::Cm(f(value)),
+            Length::In(value) => Length::In(f(value)),
+            Length::TextWidth(value) => Length::TextWidth(f(value)),
+        }
+    }
+}
+
+impl Mul<f64> for Length {
+    type Output = Length;
+    /// Scale the length, keeping its unit.
+    fn mul(self, factor: f64) -> Self::Output {
+        self.map(|value| value * factor)
+    }
+}
+
+impl Div<f64> for Length {
+    type Output = Length;
+    /// Scale the length, keeping its unit.
+    fn div(self, divisor: f64) -> Self::Output {
+        self.map(|value| value / divisor)
+    }
+}
+
+impl Neg for Length {
+    type Output = Length;
+    /// Negate the length, keeping its unit.
+    fn neg(self) -> Self::Output {
+        self.map(|value| -value)
+    }
+}
+
+#[cfg(test)]
+mod tests;
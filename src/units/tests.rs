@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+fn length_to_string() {
+    assert_eq!(Length::Pt(20.0).to_string(), String::from("20pt"));
+    assert_eq!(Length::Pt(2.5).to_string(), String::from("2.5pt"));
+    assert_eq!(Length::Pt(-2.5).to_string(), String::from("-2.5pt"));
+    assert_eq!(Length::Mm(3.0).to_string(), String::from("3mm"));
+    assert_eq!(Length::Cm(1.0).to_string(), String::from("1cm"));
+    assert_eq!(Length::Cm(1.5).to_string(), String::from("1.5cm"));
+    assert_eq!(Length::In(1.0).to_string(), String::from("1in"));
+    assert_eq!(
+        Length::TextWidth(0.8).to_string(),
+        String::from("0.8\\textwidth")
+    );
+}
+
+#[test]
+fn length_from_f64() {
+    let length: Length = 2.5.into();
+    assert_eq!(length, Length::Pt(2.5));
+}
+
+#[test]
+fn length_to_pt() {
+    assert_eq!(Length::Pt(2.0).to_pt(), Some(2.0));
+    assert_eq!(Length::In(2.0).to_pt(), Some(144.54));
+    assert!((Length::Cm(2.54).to_pt().unwrap() - 72.27).abs() < 1e-9);
+    assert!((Length::Mm(25.4).to_pt().unwrap() - 72.27).abs() < 1e-9);
+    assert!(Length::TextWidth(1.0).to_pt().is_none());
+}
+
+#[test]
+fn length_arithmetic() {
+    assert_eq!(Length::Cm(1.5) * 2.0, Length::Cm(3.0));
+    assert_eq!(Length::Mm(3.0) / 2.0, Length::Mm(1.5));
+    assert_eq!(-Length::Pt(5.0), Length::Pt(-5.0));
+    assert_eq!(Length::TextWidth(0.25) * 2.0, Length::TextWidth(0.5));
+    assert_eq!(-Length::In(1.0) * 0.5, Length::In(-0.5));
+}